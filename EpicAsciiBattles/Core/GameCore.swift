@@ -65,7 +65,7 @@ class GameCore {
         }
         
         do {
-            return try JSONDecoder().decode([BattleEvent].self, from: jsonData)
+            return try JSONDecoder().decode([LossyBattleEvent].self, from: jsonData).compactMap { $0.event }
         } catch {
             print("Failed to decode events: \(error)")
             return []
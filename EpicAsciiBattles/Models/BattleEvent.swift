@@ -10,6 +10,25 @@ enum BattleEvent: Codable {
     case vomit(actorId: UInt32, amount: UInt32, x: Int32, y: Int32)
     case statusChange(actorId: UInt32, status: String, active: Bool)
     case bump(bumperId: UInt32, bumpedId: UInt32, toX: Int32, toY: Int32)
+    case grounded(actorId: UInt32, fallDamage: UInt32, x: Int32, y: Int32)
+    case knockback(attackerId: UInt32, defenderId: UInt32, fromX: Int32, fromY: Int32, toX: Int32, toY: Int32, impactDamage: UInt32)
+    case trample(tramplerId: UInt32, victimId: UInt32, damage: UInt32, x: Int32, y: Int32)
+    case grapple(grapplerId: UInt32, targetId: UInt32)
+    case pin(grapplerId: UInt32, targetId: UInt32)
+    case thrown(throwerId: UInt32, targetId: UInt32, fromX: Int32, fromY: Int32, toX: Int32, toY: Int32, impactDamage: UInt32)
+    case breakFree(actorId: UInt32, grapplerId: UInt32)
+    case spawn(actorId: UInt32, team: UInt8, speciesId: String, x: Int32, y: Int32)
+    case wound(actorId: UInt32, partId: String, level: String)
+    case dodge(attackerId: UInt32, defenderId: UInt32)
+    case critical(attackerId: UInt32, defenderId: UInt32, partId: String, kind: String, organ: String?, flavor: String)
+    case poison(actorId: UInt32, severity: String)
+    case toxinDamage(actorId: UInt32, amount: UInt32)
+    case fade(actorId: UInt32, partId: String)
+    case dissipate(actorId: UInt32, x: Int32, y: Int32)
+    case shatter(actorId: UInt32, partId: String, gibChar: Character, debris: UInt32, x: Int32, y: Int32)
+    case auraEnter(actorId: UInt32, sourceId: UInt32, aura: String)
+    case auraLeave(actorId: UInt32, sourceId: UInt32, aura: String)
+    case deflect(attackerId: UInt32, defenderId: UInt32, partId: String)
     
     enum CodingKeys: String, CodingKey {
         case type
@@ -18,6 +37,17 @@ enum BattleEvent: Codable {
         case amount, gibChar, gib_char, x, y
         case status, active
         case bumperId, bumper_id, bumpedId, bumped_id
+        case fallDamage, fall_damage
+        case impactDamage, impact_damage
+        case tramplerId, trampler_id, victimId, victim_id
+        case grapplerId, grappler_id, targetId, target_id
+        case throwerId, thrower_id
+        case team, speciesId, species_id
+        case level
+        case kind, organ, flavor
+        case severity
+        case debris
+        case sourceId, source_id, aura
     }
     
     init(from decoder: Decoder) throws {
@@ -110,6 +140,133 @@ enum BattleEvent: Codable {
             let toY = try decodeI32(.toY, alt: .to_y)
             self = .bump(bumperId: bumperId, bumpedId: bumpedId, toX: toX, toY: toY)
             
+        case "Grounded", "grounded":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let fallDamage = try decodeU32(.fallDamage, alt: .fall_damage)
+            let x = try decodeI32(.x)
+            let y = try decodeI32(.y)
+            self = .grounded(actorId: actorId, fallDamage: fallDamage, x: x, y: y)
+            
+        case "Knockback", "knockback":
+            let attackerId = try decodeU32(.attackerId, alt: .attacker_id)
+            let defenderId = try decodeU32(.defenderId, alt: .defender_id)
+            let fromX = try decodeI32(.fromX, alt: .from_x)
+            let fromY = try decodeI32(.fromY, alt: .from_y)
+            let toX = try decodeI32(.toX, alt: .to_x)
+            let toY = try decodeI32(.toY, alt: .to_y)
+            let impactDamage = try decodeU32(.impactDamage, alt: .impact_damage)
+            self = .knockback(attackerId: attackerId, defenderId: defenderId, fromX: fromX, fromY: fromY, toX: toX, toY: toY, impactDamage: impactDamage)
+            
+        case "Trample", "trample":
+            let tramplerId = try decodeU32(.tramplerId, alt: .trampler_id)
+            let victimId = try decodeU32(.victimId, alt: .victim_id)
+            let damage = try decodeU32(.damage)
+            let x = try decodeI32(.x)
+            let y = try decodeI32(.y)
+            self = .trample(tramplerId: tramplerId, victimId: victimId, damage: damage, x: x, y: y)
+            
+        case "Grapple", "grapple":
+            let grapplerId = try decodeU32(.grapplerId, alt: .grappler_id)
+            let targetId = try decodeU32(.targetId, alt: .target_id)
+            self = .grapple(grapplerId: grapplerId, targetId: targetId)
+            
+        case "Pin", "pin":
+            let grapplerId = try decodeU32(.grapplerId, alt: .grappler_id)
+            let targetId = try decodeU32(.targetId, alt: .target_id)
+            self = .pin(grapplerId: grapplerId, targetId: targetId)
+            
+        case "Throw", "throw":
+            let throwerId = try decodeU32(.throwerId, alt: .thrower_id)
+            let targetId = try decodeU32(.targetId, alt: .target_id)
+            let fromX = try decodeI32(.fromX, alt: .from_x)
+            let fromY = try decodeI32(.fromY, alt: .from_y)
+            let toX = try decodeI32(.toX, alt: .to_x)
+            let toY = try decodeI32(.toY, alt: .to_y)
+            let impactDamage = try decodeU32(.impactDamage, alt: .impact_damage)
+            self = .thrown(throwerId: throwerId, targetId: targetId, fromX: fromX, fromY: fromY, toX: toX, toY: toY, impactDamage: impactDamage)
+            
+        case "BreakFree", "breakFree":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let grapplerId = try decodeU32(.grapplerId, alt: .grappler_id)
+            self = .breakFree(actorId: actorId, grapplerId: grapplerId)
+            
+        case "Spawn", "spawn":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let team = try container.decode(UInt8.self, forKey: .team)
+            let speciesId = try decodeString(.speciesId, alt: .species_id)
+            let x = try decodeI32(.x)
+            let y = try decodeI32(.y)
+            self = .spawn(actorId: actorId, team: team, speciesId: speciesId, x: x, y: y)
+            
+        case "Wound", "wound":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let partId = try decodeString(.partId, alt: .part_id)
+            let level = try decodeString(.level)
+            self = .wound(actorId: actorId, partId: partId, level: level)
+            
+        case "Dodge", "dodge":
+            let attackerId = try decodeU32(.attackerId, alt: .attacker_id)
+            let defenderId = try decodeU32(.defenderId, alt: .defender_id)
+            self = .dodge(attackerId: attackerId, defenderId: defenderId)
+            
+        case "Critical", "critical":
+            let attackerId = try decodeU32(.attackerId, alt: .attacker_id)
+            let defenderId = try decodeU32(.defenderId, alt: .defender_id)
+            let partId = try decodeString(.partId, alt: .part_id)
+            let kind = try decodeString(.kind)
+            let organ = try container.decodeIfPresent(String.self, forKey: .organ)
+            let flavor = try decodeString(.flavor)
+            self = .critical(attackerId: attackerId, defenderId: defenderId, partId: partId, kind: kind, organ: organ, flavor: flavor)
+            
+        case "Poison", "poison":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let severity = try decodeString(.severity)
+            self = .poison(actorId: actorId, severity: severity)
+            
+        case "ToxinDamage", "toxinDamage":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let amount = try decodeU32(.amount)
+            self = .toxinDamage(actorId: actorId, amount: amount)
+            
+        case "Fade", "fade":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let partId = try decodeString(.partId, alt: .part_id)
+            self = .fade(actorId: actorId, partId: partId)
+            
+        case "Dissipate", "dissipate":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let x = try decodeI32(.x)
+            let y = try decodeI32(.y)
+            self = .dissipate(actorId: actorId, x: x, y: y)
+            
+        case "Shatter", "shatter":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let partId = try decodeString(.partId, alt: .part_id)
+            let gibCharStr = try decodeString(.gibChar, alt: .gib_char)
+            let gibChar = gibCharStr.first ?? "*"
+            let debris = try decodeU32(.debris)
+            let x = try decodeI32(.x)
+            let y = try decodeI32(.y)
+            self = .shatter(actorId: actorId, partId: partId, gibChar: gibChar, debris: debris, x: x, y: y)
+            
+        case "AuraEnter", "auraEnter":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let sourceId = try decodeU32(.sourceId, alt: .source_id)
+            let aura = try decodeString(.aura)
+            self = .auraEnter(actorId: actorId, sourceId: sourceId, aura: aura)
+            
+        case "AuraLeave", "auraLeave":
+            let actorId = try decodeU32(.actorId, alt: .actor_id)
+            let sourceId = try decodeU32(.sourceId, alt: .source_id)
+            let aura = try decodeString(.aura)
+            self = .auraLeave(actorId: actorId, sourceId: sourceId, aura: aura)
+            
+        case "Deflect", "deflect":
+            let attackerId = try decodeU32(.attackerId, alt: .attacker_id)
+            let defenderId = try decodeU32(.defenderId, alt: .defender_id)
+            let partId = try decodeString(.partId, alt: .part_id)
+            self = .deflect(attackerId: attackerId, defenderId: defenderId, partId: partId)
+            
         default:
             throw DecodingError.dataCorruptedError(forKey: .type, in: container, debugDescription: "Unknown event type: \(type)")
         }
@@ -173,6 +330,132 @@ enum BattleEvent: Codable {
             try container.encode(bumpedId, forKey: .bumpedId)
             try container.encode(toX, forKey: .toX)
             try container.encode(toY, forKey: .toY)
+            
+        case .grounded(let actorId, let fallDamage, let x, let y):
+            try container.encode("Grounded", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(fallDamage, forKey: .fallDamage)
+            try container.encode(x, forKey: .x)
+            try container.encode(y, forKey: .y)
+            
+        case .knockback(let attackerId, let defenderId, let fromX, let fromY, let toX, let toY, let impactDamage):
+            try container.encode("Knockback", forKey: .type)
+            try container.encode(attackerId, forKey: .attackerId)
+            try container.encode(defenderId, forKey: .defenderId)
+            try container.encode(fromX, forKey: .fromX)
+            try container.encode(fromY, forKey: .fromY)
+            try container.encode(toX, forKey: .toX)
+            try container.encode(toY, forKey: .toY)
+            try container.encode(impactDamage, forKey: .impactDamage)
+            
+        case .trample(let tramplerId, let victimId, let damage, let x, let y):
+            try container.encode("Trample", forKey: .type)
+            try container.encode(tramplerId, forKey: .tramplerId)
+            try container.encode(victimId, forKey: .victimId)
+            try container.encode(damage, forKey: .damage)
+            try container.encode(x, forKey: .x)
+            try container.encode(y, forKey: .y)
+            
+        case .grapple(let grapplerId, let targetId):
+            try container.encode("Grapple", forKey: .type)
+            try container.encode(grapplerId, forKey: .grapplerId)
+            try container.encode(targetId, forKey: .targetId)
+            
+        case .pin(let grapplerId, let targetId):
+            try container.encode("Pin", forKey: .type)
+            try container.encode(grapplerId, forKey: .grapplerId)
+            try container.encode(targetId, forKey: .targetId)
+            
+        case .thrown(let throwerId, let targetId, let fromX, let fromY, let toX, let toY, let impactDamage):
+            try container.encode("Throw", forKey: .type)
+            try container.encode(throwerId, forKey: .throwerId)
+            try container.encode(targetId, forKey: .targetId)
+            try container.encode(fromX, forKey: .fromX)
+            try container.encode(fromY, forKey: .fromY)
+            try container.encode(toX, forKey: .toX)
+            try container.encode(toY, forKey: .toY)
+            try container.encode(impactDamage, forKey: .impactDamage)
+            
+        case .breakFree(let actorId, let grapplerId):
+            try container.encode("BreakFree", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(grapplerId, forKey: .grapplerId)
+            
+        case .spawn(let actorId, let team, let speciesId, let x, let y):
+            try container.encode("Spawn", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(team, forKey: .team)
+            try container.encode(speciesId, forKey: .speciesId)
+            try container.encode(x, forKey: .x)
+            try container.encode(y, forKey: .y)
+            
+        case .wound(let actorId, let partId, let level):
+            try container.encode("Wound", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(partId, forKey: .partId)
+            try container.encode(level, forKey: .level)
+            
+        case .dodge(let attackerId, let defenderId):
+            try container.encode("Dodge", forKey: .type)
+            try container.encode(attackerId, forKey: .attackerId)
+            try container.encode(defenderId, forKey: .defenderId)
+            
+        case .critical(let attackerId, let defenderId, let partId, let kind, let organ, let flavor):
+            try container.encode("Critical", forKey: .type)
+            try container.encode(attackerId, forKey: .attackerId)
+            try container.encode(defenderId, forKey: .defenderId)
+            try container.encode(partId, forKey: .partId)
+            try container.encode(kind, forKey: .kind)
+            try container.encodeIfPresent(organ, forKey: .organ)
+            try container.encode(flavor, forKey: .flavor)
+            
+        case .poison(let actorId, let severity):
+            try container.encode("Poison", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(severity, forKey: .severity)
+            
+        case .toxinDamage(let actorId, let amount):
+            try container.encode("ToxinDamage", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(amount, forKey: .amount)
+            
+        case .fade(let actorId, let partId):
+            try container.encode("Fade", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(partId, forKey: .partId)
+            
+        case .dissipate(let actorId, let x, let y):
+            try container.encode("Dissipate", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(x, forKey: .x)
+            try container.encode(y, forKey: .y)
+            
+        case .shatter(let actorId, let partId, let gibChar, let debris, let x, let y):
+            try container.encode("Shatter", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(partId, forKey: .partId)
+            try container.encode(String(gibChar), forKey: .gibChar)
+            try container.encode(debris, forKey: .debris)
+            try container.encode(x, forKey: .x)
+            try container.encode(y, forKey: .y)
+            
+        case .auraEnter(let actorId, let sourceId, let aura):
+            try container.encode("AuraEnter", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(sourceId, forKey: .sourceId)
+            try container.encode(aura, forKey: .aura)
+            
+        case .auraLeave(let actorId, let sourceId, let aura):
+            try container.encode("AuraLeave", forKey: .type)
+            try container.encode(actorId, forKey: .actorId)
+            try container.encode(sourceId, forKey: .sourceId)
+            try container.encode(aura, forKey: .aura)
+            
+        case .deflect(let attackerId, let defenderId, let partId):
+            try container.encode("Deflect", forKey: .type)
+            try container.encode(attackerId, forKey: .attackerId)
+            try container.encode(defenderId, forKey: .defenderId)
+            try container.encode(partId, forKey: .partId)
         }
     }
     
@@ -263,10 +546,134 @@ enum BattleEvent: Codable {
             let bumped = name(bumpedId)
             let desc = ["slams into", "crashes into", "barrels into", "collides with", "shoves"].randomElement()!
             return "💥 \(bumper) \(desc) \(bumped)!"
+            
+        case .grounded(let actorId, _, _, _):
+            let actor = name(actorId)
+            let desc = ["crashes to the ground", "tumbles out of the sky", "plummets to the earth"].randomElement()!
+            return "🪶 \(actor) \(desc)!"
+            
+        case .knockback(let attackerId, let defenderId, _, _, _, _, let impactDamage):
+            let attacker = name(attackerId)
+            let defender = name(defenderId)
+            if impactDamage > 0 {
+                return "💥 \(attacker) slams \(defender) into whatever stands behind!"
+            }
+            let desc = ["knocks", "sends", "bowls"].randomElement()!
+            return "\(attacker) \(desc) \(defender) flying back!"
+            
+        case .trample(let tramplerId, let victimId, _, _, _):
+            let trampler = name(tramplerId)
+            let victim = name(victimId)
+            let desc = ["tramples", "stomps over", "runs down", "crushes underfoot"].randomElement()!
+            return "🦶 \(trampler) \(desc) \(victim)!"
+            
+        case .grapple(let grapplerId, let targetId):
+            let grappler = name(grapplerId)
+            let target = name(targetId)
+            let desc = ["seizes", "grabs hold of", "latches onto", "wrestles with"].randomElement()!
+            return "🤼 \(grappler) \(desc) \(target)!"
+            
+        case .pin(let grapplerId, let targetId):
+            let grappler = name(grapplerId)
+            let target = name(targetId)
+            let desc = ["pins", "forces down", "wrestles to the ground"].randomElement()!
+            return "\(grappler) \(desc) \(target)!"
+            
+        case .thrown(let throwerId, let targetId, _, _, _, _, _):
+            let thrower = name(throwerId)
+            let target = name(targetId)
+            let desc = ["hurls", "flings", "tosses", "throws"].randomElement()!
+            return "💥 \(thrower) \(desc) \(target) across the arena!"
+            
+        case .breakFree(let actorId, let grapplerId):
+            let actor = name(actorId)
+            let grappler = name(grapplerId)
+            let desc = ["breaks free of", "wriggles out of", "tears loose from"].randomElement()!
+            return "\(actor) \(desc) \(grappler)'s grip!"
+            
+        case .spawn(let actorId, _, _, _, _):
+            let actor = name(actorId)
+            let desc = ["joins the fray", "charges into the arena", "arrives as reinforcement"].randomElement()!
+            return "📯 \(actor) \(desc)!"
+            
+        case .wound(let actorId, let partId, let level):
+            let actor = name(actorId)
+            let part = partName(partId)
+            return "\(actor)'s \(part) is \(level)."
+            
+        case .dodge(_, let defenderId):
+            let defender = name(defenderId)
+            let desc = ["darts aside", "ducks out of the way", "sidesteps the blow", "twists away"].randomElement()!
+            return "\(defender) \(desc)!"
+            
+        case .critical(let attackerId, _, _, _, _, let flavor):
+            let attacker = name(attackerId)
+            return "⚡ \(attacker)'s \(flavor.prefix(1).lowercased() + flavor.dropFirst())"
+            
+        case .poison(let actorId, let severity):
+            let actor = name(actorId)
+            switch severity {
+            case "clear": return "\(actor) shakes off the venom."
+            case "mild": return "🐍 \(actor) is poisoned!"
+            case "severe": return "🐍 \(actor) reels as the venom spreads!"
+            case "paralyzing": return "🐍 \(actor) is paralyzed by venom!"
+            default: return "🐍 \(actor) is poisoned!"
+            }
+            
+        case .toxinDamage:
+            // Don't log venom ticks - too spammy
+            return ""
+            
+        case .fade(let actorId, let partId):
+            let actor = name(actorId)
+            let part = partName(partId)
+            let desc = ["fades away", "dissolves into mist", "flickers out"].randomElement()!
+            return "👻 \(actor)'s \(part) \(desc)!"
+            
+        case .dissipate(let actorId, _, _):
+            let actor = name(actorId)
+            let desc = ["dissipates", "vanishes into nothing", "unravels into the air"].randomElement()!
+            return "💀 \(actor) \(desc)!"
+            
+        case .shatter(let actorId, let partId, _, _, _, _):
+            let actor = name(actorId)
+            let part = partName(partId)
+            let desc = ["shatters", "bursts into rubble", "cracks apart"].randomElement()!
+            return "💥 \(actor)'s \(part) \(desc)!"
+            
+        case .auraEnter(let actorId, let sourceId, let aura):
+            let actor = name(actorId)
+            let source = name(sourceId)
+            switch aura {
+            case "fear": return "😨 \(actor) quails before \(source)!"
+            case "rally": return "\(actor) takes heart beside \(source)."
+            case "heat": return "🔥 \(actor) is scorched by \(source)'s heat!"
+            default: return "\(actor) falls under \(source)'s \(aura) aura."
+            }
+            
+        case .auraLeave:
+            return "" // Don't log leaving an aura
+            
+        case .deflect(let attackerId, let defenderId, let partId):
+            let attacker = name(attackerId)
+            let defender = name(defenderId)
+            let part = partName(partId)
+            let desc = ["glances off", "skids off", "rings off"].randomElement()!
+            return "\(attacker)'s blow \(desc) \(defender)'s armored \(part)."
         }
     }
 }
 
+/// Decodes a single event, leaving it empty when the simulation sends a
+/// type this build doesn't know, so one new event can't drop a whole tick
+struct LossyBattleEvent: Decodable {
+    let event: BattleEvent?
+    
+    init(from decoder: Decoder) throws {
+        event = try? BattleEvent(from: decoder)
+    }
+}
+
 /// Battle state snapshot
 struct BattleState: Codable {
     let seed: UInt64
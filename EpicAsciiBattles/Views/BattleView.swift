@@ -376,6 +376,97 @@ struct BattleView: View {
                     addBlip(x: x, y: y, glyph: "!", color: .orange, ttl: 0.25 * motionScale)
                 }
                 
+            case .grounded(let actorId, _, let x, let y):
+                addHitFlash(actorId: actorId, duration: 0.15 * motionScale)
+                addBlip(x: x, y: y, glyph: "v", color: .orange, ttl: 0.4 * motionScale)
+                
+            case .knockback(_, let defenderId, _, _, let toX, let toY, let impactDamage):
+                addHitFlash(actorId: defenderId, duration: 0.15 * motionScale)
+                addBlip(x: toX, y: toY, glyph: impactDamage > 0 ? "#" : "*", color: .yellow, ttl: 0.3 * motionScale)
+                
+            case .trample(_, let victimId, _, let x, let y):
+                addHitFlash(actorId: victimId, duration: 0.2 * motionScale)
+                addBlip(x: x, y: y, glyph: "x", color: .red, ttl: 0.4 * gore.particleDuration * motionScale)
+                addBackgroundTint(x: x, y: y, radius: 0, color: .red, opacity: gore.tintOpacity * 0.3, duration: 0.5)
+                
+            case .grapple(_, let targetId):
+                if let (x, y) = actorPosition(targetId, state: state) {
+                    addBlip(x: x, y: y, glyph: "&", color: .yellow, ttl: 0.3 * motionScale)
+                }
+                
+            case .pin(_, let targetId):
+                if let (x, y) = actorPosition(targetId, state: state) {
+                    addHitFlash(actorId: targetId, duration: 0.15 * motionScale)
+                    addBlip(x: x, y: y, glyph: "_", color: .yellow, ttl: 0.3 * motionScale)
+                }
+                
+            case .thrown(_, let targetId, _, _, let toX, let toY, let impactDamage):
+                addHitFlash(actorId: targetId, duration: 0.2 * motionScale)
+                addBlip(x: toX, y: toY, glyph: impactDamage > 0 ? "#" : "*", color: .orange, ttl: 0.4 * motionScale)
+                
+            case .breakFree:
+                break
+                
+            case .spawn(_, _, _, let x, let y):
+                addBlip(x: x, y: y, glyph: "+", color: .cyan, ttl: 0.5 * motionScale)
+                
+            case .wound:
+                break
+                
+            case .dodge(_, let defenderId):
+                if let (x, y) = actorPosition(defenderId, state: state) {
+                    addBlip(x: x, y: y, glyph: "'", color: .cyan, ttl: 0.25 * motionScale)
+                }
+                
+            case .critical(_, let defenderId, _, _, _, _):
+                if let (x, y) = actorPosition(defenderId, state: state) {
+                    addHitFlash(actorId: defenderId, duration: 0.25 * motionScale)
+                    addBlip(x: x, y: y, glyph: "!", color: .red, ttl: 0.5 * motionScale)
+                    addBackgroundTint(x: x, y: y, radius: 1, color: .red, opacity: gore.tintOpacity * 0.4, duration: 0.6)
+                }
+                
+            case .poison(let actorId, let severity):
+                if severity != "clear", let (x, y) = actorPosition(actorId, state: state) {
+                    addBlip(x: x, y: y, glyph: "%", color: .green, ttl: 0.4 * motionScale)
+                }
+                
+            case .toxinDamage(let actorId, _):
+                if let (x, y) = actorPosition(actorId, state: state) {
+                    addBlip(x: x, y: y, glyph: "~", color: .green.opacity(0.8), ttl: 0.3 * motionScale)
+                }
+                
+            case .fade(let actorId, _):
+                if let (x, y) = actorPosition(actorId, state: state) {
+                    addBlip(x: x, y: y, glyph: "░", color: .gray, ttl: 0.6 * motionScale)
+                }
+                
+            case .dissipate(_, let x, let y):
+                // Ethereal deaths leave no corpse mark behind
+                addBlip(x: x, y: y, glyph: "░", color: .gray, ttl: 1.0 * motionScale)
+                
+            case .shatter(_, _, let gibChar, _, let x, let y):
+                // Rubble scatters into the surrounding cells
+                for _ in 0..<3 {
+                    let gx = max(0, min(state.grid.width - 1, x + Int32.random(in: -1...1)))
+                    let gy = max(0, min(state.grid.height - 1, y + Int32.random(in: -1...1)))
+                    addBlip(x: gx, y: gy, glyph: String(gibChar), color: .gray, ttl: 0.6 * motionScale)
+                }
+                persistentMarks.append(GridMark(x: x, y: y, glyph: String(gibChar), color: .gray.opacity(0.7), isPermanent: true))
+                
+            case .auraEnter(let actorId, _, let aura):
+                if let (x, y) = actorPosition(actorId, state: state) {
+                    let color: Color = aura == "heat" ? .orange : (aura == "fear" ? .purple : .cyan)
+                    addBackgroundTint(x: x, y: y, radius: 0, color: color, opacity: 0.3, duration: 0.6)
+                }
+                
+            case .auraLeave:
+                break
+                
+            case .deflect(_, let defenderId, _):
+                if let (x, y) = actorPosition(defenderId, state: state) {
+                    addBlip(x: x, y: y, glyph: "#", color: .gray, ttl: 0.25 * motionScale)
+                }
+                
             case .move(let actorId, let fromX, let fromY, let toX, let toY):
                 let fromKey = Int(fromY) * gridWidth + Int(fromX)
                 let toKey = Int(toY) * gridWidth + Int(toX)
@@ -438,6 +529,44 @@ struct BattleView: View {
             return (.gray, false)
         case .bump:
             return (DFColors.yellow, false)
+        case .grounded:
+            return (Color(red: 1.0, green: 0.4, blue: 0.2), false)
+        case .knockback(_, _, _, _, _, _, let impactDamage):
+            return (DFColors.yellow, impactDamage > 0)
+        case .trample:
+            return (Color(red: 1.0, green: 0.4, blue: 0.2), false)
+        case .grapple:
+            return (DFColors.yellow, false)
+        case .pin:
+            return (DFColors.yellow, false)
+        case .thrown(_, _, _, _, _, _, let impactDamage):
+            return (Color(red: 1.0, green: 0.4, blue: 0.2), impactDamage > 0)
+        case .breakFree:
+            return (.gray, false)
+        case .spawn:
+            return (.cyan, false)
+        case .wound(_, _, let level):
+            return (level == "mangled" ? Color(red: 0.8, green: 0.2, blue: 0.2) : .gray, false)
+        case .dodge:
+            return (.gray, false)
+        case .critical:
+            return (Color(red: 1.0, green: 0.3, blue: 0.3), true)
+        case .poison(_, let severity):
+            return (severity == "clear" ? .gray : Color(red: 0.5, green: 0.8, blue: 0.3), severity == "paralyzing")
+        case .toxinDamage:
+            return (Color(red: 0.5, green: 0.8, blue: 0.3), false)
+        case .fade:
+            return (Color(red: 0.7, green: 0.7, blue: 0.9), true)
+        case .dissipate:
+            return (DFColors.lred, true)
+        case .shatter:
+            return (Color(red: 1.0, green: 0.4, blue: 0.2), true)
+        case .auraEnter(_, _, let aura):
+            return (aura == "fear" ? DFColors.yellow : .gray, false)
+        case .auraLeave:
+            return (.gray, false)
+        case .deflect:
+            return (.gray, false)
         case .move:
             return (.gray, false)
         }
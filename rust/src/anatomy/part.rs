@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::sim::attack::{AttackOverride, DamageType};
+use crate::anatomy::Tag;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Part {
@@ -10,8 +12,103 @@ pub struct Part {
     pub hp: i32,
     pub max_hp: i32,
     pub armor: i32,
+    /// Open bleeding, in thousandths of the actor's blood lost per tick
     pub bleed_rate: u32,
+    /// How freely the part bleeds when wounded, from the species definition
+    #[serde(default)]
+    pub base_bleed_rate: u32,
     pub hit_weight: u32,
+    #[serde(default)]
+    pub resistances: Resistances,
+    /// Explicit height on the body; inferred from tags when omitted
+    #[serde(default)]
+    pub zone: Option<BodyZone>,
+    /// Attacks the part makes in place of those derived from its tags
+    #[serde(default)]
+    pub attacks_provided: Option<Vec<AttackOverride>>,
+}
+
+/// How high on the body a part sits, which decides who can reach it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BodyZone {
+    Low,
+    Mid,
+    High,
+}
+
+/// How badly hurt a part is, from its remaining health. Parts only get
+/// worse over a battle; destruction is handled separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WoundLevel {
+    Healthy,
+    Bruised,
+    Wounded,
+    Mangled,
+}
+
+impl WoundLevel {
+    /// Percent of normal function a part keeps at this level
+    pub fn function_percent(&self) -> u32 {
+        match self {
+            WoundLevel::Healthy | WoundLevel::Bruised => 100,
+            WoundLevel::Wounded => 75,
+            WoundLevel::Mangled => 50,
+        }
+    }
+}
+
+/// Percent of each damage type a part actually takes (100 = no resistance)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Resistances {
+    pub pierce: u32,
+    pub slash: u32,
+    pub blunt: u32,
+}
+
+impl Default for Resistances {
+    fn default() -> Self {
+        Self {
+            pierce: 100,
+            slash: 100,
+            blunt: 100,
+        }
+    }
+}
+
+impl Resistances {
+    /// Default resistances from covering tags. Several coverings stack.
+    pub fn from_tags(tags: &[String]) -> Self {
+        let mut resistances = Self::default();
+        for tag in tags {
+            let (pierce, slash, blunt) = match tag.as_str() {
+                // Feathers cushion blows, scales turn blades
+                "feathered" => (100, 90, 75),
+                "furred" => (95, 85, 90),
+                "scaled" => (85, 70, 100),
+                "armor" | "armored" => (60, 50, 90),
+                // Stone turns blades but cracks under heavy blows
+                "stone" => (50, 40, 120),
+                // Ethereal matter lets physical blows pass mostly through
+                "ethereal" => (30, 30, 20),
+                _ => continue,
+            };
+            resistances.pierce = resistances.pierce * pierce / 100;
+            resistances.slash = resistances.slash * slash / 100;
+            resistances.blunt = resistances.blunt * blunt / 100;
+        }
+        resistances
+    }
+    
+    /// Percent of damage of the given type that gets through
+    pub fn against(&self, damage_type: DamageType) -> u32 {
+        match damage_type {
+            DamageType::Pierce => self.pierce,
+            DamageType::Slash => self.slash,
+            DamageType::Blunt => self.blunt,
+        }
+    }
 }
 
 impl Part {
@@ -26,7 +123,11 @@ impl Part {
             max_hp: 10,
             armor: 0,
             bleed_rate: 0,
+            base_bleed_rate: 0,
             hit_weight: 1,
+            resistances: Resistances::default(),
+            zone: None,
+            attacks_provided: None,
         }
     }
     
@@ -34,7 +135,161 @@ impl Part {
         self.hp <= 0
     }
     
+    /// Wound level from the share of health left: bruised below 90%,
+    /// wounded below 60%, mangled below 30%
+    pub fn wound_level(&self) -> WoundLevel {
+        let percent = self.hp.max(0) * 100 / self.max_hp.max(1);
+        if percent >= 90 {
+            WoundLevel::Healthy
+        } else if percent >= 60 {
+            WoundLevel::Bruised
+        } else if percent >= 30 {
+            WoundLevel::Wounded
+        } else {
+            WoundLevel::Mangled
+        }
+    }
+    
     pub fn has_tag(&self, tag: &str) -> bool {
         self.tags.iter().any(|t| t == tag)
     }
+    
+    /// Whether the part is covered in this material (feathers, fur, scales
+    /// or armor plates)
+    pub fn has_covering(&self, covering: Tag) -> bool {
+        covering.is_covering() && self.tags.iter().any(|t| Tag::from_string(t) == Some(covering))
+    }
+    
+    /// Insubstantial matter: no blood, no organs, nothing left behind
+    pub fn is_ethereal(&self) -> bool {
+        self.has_tag("ethereal")
+    }
+    
+    /// Living rock: cracks instead of bleeding and feels no pain
+    pub fn is_stone(&self) -> bool {
+        self.has_tag("stone")
+    }
+    
+    /// Flesh that bleeds when cut; stone and ethereal parts don't
+    pub fn bleeds(&self) -> bool {
+        !self.is_ethereal() && !self.is_stone()
+    }
+    
+    /// Armor the part still has. Stone cracks as it's wounded, losing
+    /// armor in step with its wound level.
+    pub fn current_armor(&self) -> i32 {
+        if self.is_stone() {
+            self.armor * self.wound_level().function_percent() as i32 / 100
+        } else {
+            self.armor
+        }
+    }
+    
+    /// Check if this part is an instance of a species part id
+    /// (e.g. `leg_1` is an instance of `leg`)
+    pub fn is_instance_of(&self, base_id: &str) -> bool {
+        is_instance_id(&self.part_id, base_id)
+    }
+    
+    /// Check if this part hangs off any instance of a species part id
+    pub fn attaches_to(&self, base_id: &str) -> bool {
+        self.attachments.iter().any(|a| is_instance_id(a, base_id))
+    }
+    
+    /// Glyph used for this part when it lies severed on the ground
+    pub fn gib_glyph(&self) -> char {
+        if self.is_stone() {
+            // Stone only ever comes off as rubble
+            '*'
+        } else if self.has_tag("head") {
+            'o'
+        } else if self.has_tag("wing") || self.has_tag("flight") {
+            '{'
+        } else if self.has_tag("tail") {
+            '~'
+        } else if self.has_tag("leg") || self.has_tag("arm") || self.has_tag("locomotion") {
+            '/'
+        } else if self.has_tag("neck") {
+            '|'
+        } else if self.attaches_to("head") {
+            '\''
+        } else {
+            '%'
+        }
+    }
+    
+    /// Internal organs a deep strike to this part can reach. Brains are
+    /// tagged on heads; a vital torso holds the heart and lungs unless its
+    /// organs are tagged explicitly. Stone torsos are solid rock.
+    pub fn organs(&self) -> Vec<&'static str> {
+        let mut organs = Vec::new();
+        if self.is_ethereal() {
+            return organs;
+        }
+        if self.has_tag("brain") {
+            organs.push("brain");
+        }
+        let vital_torso = self.has_tag("torso") && self.has_tag("vital") && !self.is_stone();
+        let tagged = self.has_tag("heart") || self.has_tag("lung");
+        if self.has_tag("heart") || (vital_torso && !tagged) {
+            organs.push("heart");
+        }
+        if self.has_tag("lung") || (vital_torso && !tagged) {
+            organs.push("lung");
+        }
+        organs
+    }
+    
+    /// Height on the body: head-region parts are high, legs and tails low
+    pub fn zone(&self) -> BodyZone {
+        if let Some(zone) = self.zone {
+            zone
+        } else if self.is_upper_body() {
+            BodyZone::High
+        } else if self.is_lower_body() || self.has_tag("tail") {
+            BodyZone::Low
+        } else {
+            BodyZone::Mid
+        }
+    }
+    
+    /// Head-region parts: the head, neck and anything mounted on the head
+    pub fn is_upper_body(&self) -> bool {
+        self.has_tag("head")
+            || self.has_tag("neck")
+            || self.attaches_to("head")
+    }
+    
+    /// Legs, feet and anything mounted on a leg
+    pub fn is_lower_body(&self) -> bool {
+        self.has_tag("leg")
+            || self.has_tag("foot")
+            || self.has_tag("locomotion")
+            || self.attaches_to("leg")
+    }
+}
+
+/// Check if a part instance id belongs to a species part id
+fn is_instance_id(part_id: &str, base_id: &str) -> bool {
+    part_id == base_id
+        || part_id.strip_prefix(base_id).is_some_and(|rest| {
+            rest.strip_prefix('_').is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()))
+        })
+}
+
+#[cfg(test)]
+impl Part {
+    /// A default part carrying the given tags, for test fixtures
+    pub(crate) fn tagged(part_id: &str, tags: &[&str]) -> Self {
+        let mut part = Self::new(part_id.to_string(), part_id.to_string());
+        part.tags = tags.iter().map(|t| t.to_string()).collect();
+        part
+    }
+    
+    /// A tagged test part hanging from the given parts
+    pub(crate) fn attached(part_id: &str, tags: &[&str], attachments: &[&str]) -> Self {
+        let mut part = Self::tagged(part_id, tags);
+        part.attachments = attachments.iter().map(|a| a.to_string()).collect();
+        part
+    }
 }
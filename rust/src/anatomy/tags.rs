@@ -59,7 +59,8 @@ impl Tag {
             "feathered" => Some(Tag::Feathered),
             "scaled" => Some(Tag::Scaled),
             "furred" => Some(Tag::Furred),
-            "armored" => Some(Tag::Armored),
+            // Species YAML writes armor plates as plain "armor"
+            "armored" | "armor" => Some(Tag::Armored),
             "locomotion" => Some(Tag::Locomotion),
             "flight" => Some(Tag::Flight),
             "grasp" => Some(Tag::Grasp),
@@ -78,4 +79,9 @@ impl Tag {
             _ => None,
         }
     }
+    
+    /// Whether the tag is a body covering that changes how blows land
+    pub fn is_covering(&self) -> bool {
+        matches!(self, Tag::Feathered | Tag::Scaled | Tag::Furred | Tag::Armored)
+    }
 }
@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use crate::anatomy::part::WoundLevel;
+use crate::sim::critical::CriticalKind;
+use crate::sim::venom::ToxinSeverity;
+use crate::sim::aura::AuraKind;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
@@ -17,10 +21,48 @@ pub enum BattleEvent {
         damage: u32,
         attack_name: String,
     },
+    /// A hit landed as a critical: through the armor, into an organ or
+    /// through a limb
+    Critical {
+        attacker_id: u32,
+        defender_id: u32,
+        part_id: String,
+        kind: CriticalKind,
+        organ: Option<String>,
+        flavor: String,
+    },
+    /// The defender dodged an attack that would otherwise have landed
+    Dodge {
+        attacker_id: u32,
+        defender_id: u32,
+    },
+    /// A glancing blow skidded off armor plates without doing harm
+    Deflect {
+        attacker_id: u32,
+        defender_id: u32,
+        part_id: String,
+    },
     Bleed {
         actor_id: u32,
         amount: u32,
     },
+    /// Venom in an actor's blood reached a new severity; `clear` means the
+    /// poisoning has worn off
+    Poison {
+        actor_id: u32,
+        severity: ToxinSeverity,
+    },
+    /// Health lost to venom this tick
+    ToxinDamage {
+        actor_id: u32,
+        amount: u32,
+    },
+    /// A part took enough damage to reach a worse wound level
+    Wound {
+        actor_id: u32,
+        part_id: String,
+        level: WoundLevel,
+    },
     Sever {
         actor_id: u32,
         part_id: String,
@@ -33,17 +75,109 @@ pub enum BattleEvent {
         x: i32,
         y: i32,
     },
+    /// An ethereal part faded away instead of being severed
+    Fade {
+        actor_id: u32,
+        part_id: String,
+    },
+    /// A stone part burst apart, throwing `debris` damage at everyone
+    /// adjacent and leaving `gib_char` rubble behind
+    Shatter {
+        actor_id: u32,
+        part_id: String,
+        gib_char: char,
+        debris: u32,
+        x: i32,
+        y: i32,
+    },
+    /// An ethereal actor died, dissipating without leaving a corpse
+    Dissipate {
+        actor_id: u32,
+        x: i32,
+        y: i32,
+    },
+    /// A reinforcement entered the arena mid-battle
+    Spawn {
+        actor_id: u32,
+        team: u8,
+        species_id: String,
+        x: i32,
+        y: i32,
+    },
     Vomit {
         actor_id: u32,
         amount: u32,
         x: i32,
         y: i32,
     },
+    /// An actor came within reach of another actor's aura
+    AuraEnter {
+        actor_id: u32,
+        source_id: u32,
+        aura: AuraKind,
+    },
+    /// An actor is no longer under another actor's aura: it moved away, or
+    /// the aura faded
+    AuraLeave {
+        actor_id: u32,
+        source_id: u32,
+        aura: AuraKind,
+    },
     StatusChange {
         actor_id: u32,
         status: String,
         active: bool,
     },
+    /// A flying actor lost its last flight part mid-air and fell
+    Grounded {
+        actor_id: u32,
+        fall_damage: u32,
+        x: i32,
+        y: i32,
+    },
+    /// A heavy hit threw the defender backwards, possibly into an obstacle
+    Knockback {
+        attacker_id: u32,
+        defender_id: u32,
+        from_x: i32,
+        from_y: i32,
+        to_x: i32,
+        to_y: i32,
+        impact_damage: u32,
+    },
+    /// A much heavier mover ran over a tiny enemy
+    Trample {
+        trampler_id: u32,
+        victim_id: u32,
+        damage: u32,
+        x: i32,
+        y: i32,
+    },
+    /// A grasping actor seized an adjacent enemy
+    Grapple {
+        grappler_id: u32,
+        target_id: u32,
+    },
+    /// A held actor was pinned to the ground
+    Pin {
+        grappler_id: u32,
+        target_id: u32,
+    },
+    /// A grappler hurled the actor it was holding
+    Throw {
+        thrower_id: u32,
+        target_id: u32,
+        from_x: i32,
+        from_y: i32,
+        to_x: i32,
+        to_y: i32,
+        impact_damage: u32,
+    },
+    /// A held actor broke out of a grapple
+    BreakFree {
+        actor_id: u32,
+        grappler_id: u32,
+    },
     /// Two actors collided and one was bumped to a new position
     Bump {
         bumper_id: u32,
@@ -18,9 +18,36 @@ pub enum Action {
     Wait {
         actor_id: u32,
     },
+    TakeOff {
+        actor_id: u32,
+    },
+    Land {
+        actor_id: u32,
+    },
+    Grapple {
+        attacker_id: u32,
+        target_id: u32,
+    },
+    Pin {
+        actor_id: u32,
+    },
+    Tear {
+        actor_id: u32,
+    },
+    Throw {
+        actor_id: u32,
+    },
+    Struggle {
+        actor_id: u32,
+    },
 }
 
 impl Action {
+    /// Energy spent to step one cell
+    pub const MOVE_COST: u32 = 10;
+    /// Energy spent on a single attack
+    pub const ATTACK_COST: u32 = 12;
+    
     pub fn attack(attacker_id: u32, target_id: u32, attack_id: String) -> Self {
         Action::Attack {
             attacker_id,
@@ -45,12 +72,68 @@ impl Action {
         Action::Wait { actor_id }
     }
     
+    pub fn take_off(actor_id: u32) -> Self {
+        Action::TakeOff { actor_id }
+    }
+    
+    pub fn land(actor_id: u32) -> Self {
+        Action::Land { actor_id }
+    }
+    
+    pub fn grapple(attacker_id: u32, target_id: u32) -> Self {
+        Action::Grapple {
+            attacker_id,
+            target_id,
+        }
+    }
+    
+    pub fn pin(actor_id: u32) -> Self {
+        Action::Pin { actor_id }
+    }
+    
+    pub fn tear(actor_id: u32) -> Self {
+        Action::Tear { actor_id }
+    }
+    
+    pub fn throw(actor_id: u32) -> Self {
+        Action::Throw { actor_id }
+    }
+    
+    pub fn struggle(actor_id: u32) -> Self {
+        Action::Struggle { actor_id }
+    }
+    
     pub fn actor_id(&self) -> u32 {
         match self {
             Action::Attack { attacker_id, .. } => *attacker_id,
             Action::Move { actor_id, .. } => *actor_id,
             Action::Defend { actor_id } => *actor_id,
             Action::Wait { actor_id } => *actor_id,
+            Action::TakeOff { actor_id } => *actor_id,
+            Action::Land { actor_id } => *actor_id,
+            Action::Grapple { attacker_id, .. } => *attacker_id,
+            Action::Pin { actor_id } => *actor_id,
+            Action::Tear { actor_id } => *actor_id,
+            Action::Throw { actor_id } => *actor_id,
+            Action::Struggle { actor_id } => *actor_id,
+        }
+    }
+    
+    /// Energy this action consumes. Zero-cost actions end the actor's turn
+    /// for the current tick without spending anything.
+    pub fn energy_cost(&self) -> u32 {
+        match self {
+            Action::Attack { .. }
+            | Action::Grapple { .. }
+            | Action::Pin { .. }
+            | Action::Tear { .. }
+            | Action::Throw { .. } => Self::ATTACK_COST,
+            Action::Move { .. }
+            | Action::TakeOff { .. }
+            | Action::Land { .. }
+            | Action::Struggle { .. }
+            | Action::Defend { .. } => Self::MOVE_COST,
+            Action::Wait { .. } => 0,
         }
     }
 }
@@ -1,6 +1,21 @@
 use serde::{Deserialize, Serialize};
 use crate::anatomy::part::Part;
-use crate::sim::Attack;
+use crate::sim::{Action, Attack, Weapon};
+use crate::sim::attack::AttackTemplate;
+use crate::sim::venom::{MAX_TOXIN, PARALYSIS_TOXIN};
+use crate::sim::aura::Aura;
+
+/// Energy gained per tick for each point of speed
+pub const ENERGY_PER_SPEED: u32 = 2;
+/// Speed every actor keeps, even rooted or legless, so it can still strike
+/// what comes within reach
+pub const MIN_SPEED: u32 = 1;
+/// Maximum energy an actor can bank between ticks
+pub const MAX_ENERGY: u32 = Action::ATTACK_COST * 2;
+/// Stamina spent each tick to stay airborne
+pub const FLIGHT_STAMINA_COST: u32 = 8;
+/// Blood volume per gram of body mass (70 ml/kg)
+pub const BLOOD_UL_PER_G: u32 = 70;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Actor {
@@ -13,6 +28,9 @@ pub struct Actor {
     pub y: i32,
     pub hp: i32,
     pub max_hp: i32,
+    /// Health of every part the species grows, before any are lost. Weapon
+    /// parts are sized against it.
+    pub body_hp: i32,
     #[serde(rename = "is_alive")]
     pub alive: bool,
     pub parts: Vec<Part>,
@@ -20,6 +38,40 @@ pub struct Actor {
     pub max_stamina: u32,
     pub speed: u32,
     pub morale: u32,
+    /// Body mass in grams
+    pub mass_g: u32,
+    /// Action points accumulated from speed, spent on moves and attacks
+    pub energy: u32,
+    /// Flying above the ground layer (requires working flight parts)
+    pub airborne: bool,
+    /// Id of the enemy this actor is holding
+    pub grappling: Option<u32>,
+    /// Id of the enemy holding this actor
+    pub grappled_by: Option<u32>,
+    /// Held down by its grappler: cannot move or attack
+    pub pinned: bool,
+    /// Accumulated pain; fades a little every tick
+    pub pain: u32,
+    /// Resistance to pain from 0 (feels everything) to 100 (feels nothing)
+    pub pain_tolerance: u32,
+    /// Ticks left before a stunned actor can act again
+    pub stunned_ticks: u32,
+    /// Collapsed from pain until it fades
+    pub in_shock: bool,
+    /// Blood left, in microlitres
+    pub blood_ul: u32,
+    /// Blood volume when unhurt, scaled by body mass
+    pub max_blood_ul: u32,
+    /// Passed out from blood loss; blood doesn't come back mid-battle
+    pub fainted: bool,
+    /// Guarding its head and vitals until its next action
+    pub defending: bool,
+    /// Venom in the blood; metabolized a little every tick
+    pub toxin: u32,
+    /// Builds from gut wounds, pain, venom and exhaustion until the actor vomits
+    pub nausea: u32,
+    /// Auras radiating from this actor, from its species
+    pub auras: Vec<Aura>,
 }
 
 impl Actor {
@@ -34,12 +86,30 @@ impl Actor {
             y,
             hp: 100,
             max_hp: 100,
+            body_hp: 0,
             alive: true,
             parts: Vec::new(),
             stamina: 100,
             max_stamina: 100,
             speed: 5,
             morale: 100,
+            mass_g: 1000,
+            energy: 0,
+            airborne: false,
+            grappling: None,
+            grappled_by: None,
+            pinned: false,
+            pain: 0,
+            pain_tolerance: 50,
+            stunned_ticks: 0,
+            in_shock: false,
+            blood_ul: 1000 * BLOOD_UL_PER_G,
+            max_blood_ul: 1000 * BLOOD_UL_PER_G,
+            fainted: false,
+            defending: false,
+            toxin: 0,
+            nausea: 0,
+            auras: Vec::new(),
         }
     }
     
@@ -55,6 +125,126 @@ impl Actor {
         }
     }
     
+    /// Accumulate action energy for this tick based on current speed.
+    /// Wounded legs slow the actor down, but never below `MIN_SPEED`.
+    pub fn gain_energy(&mut self) {
+        let speed = (self.speed * self.function_percent("locomotion")).div_ceil(100).max(MIN_SPEED);
+        self.energy = (self.energy + speed * ENERGY_PER_SPEED).min(MAX_ENERGY);
+    }
+    
+    /// Average function left across the parts with a tag (100 if none are hurt
+    /// or the actor has none)
+    pub fn function_percent(&self, tag: &str) -> u32 {
+        let parts: Vec<&Part> = self.parts.iter().filter(|p| p.has_tag(tag)).collect();
+        if parts.is_empty() {
+            return 100;
+        }
+        parts.iter().map(|p| p.wound_level().function_percent()).sum::<u32>() / parts.len() as u32
+    }
+    
+    /// Moving on a wounded or mangled leg
+    pub fn is_limping(&self) -> bool {
+        self.function_percent("locomotion") < 100
+    }
+    
+    /// Stamina spent each tick to stay airborne; wounded wings work harder
+    pub fn flight_stamina_cost(&self) -> u32 {
+        FLIGHT_STAMINA_COST * 100 / self.function_percent("flight").max(1)
+    }
+    
+    /// Check if the actor has enough energy banked to pay for an action
+    pub fn can_afford(&self, cost: u32) -> bool {
+        self.energy >= cost
+    }
+    
+    /// Spend energy on an action
+    pub fn spend_energy(&mut self, cost: u32) {
+        self.energy = self.energy.saturating_sub(cost);
+    }
+    
+    /// Stunned, in shock, fainted or paralyzed: the actor can't act this tick
+    pub fn is_incapacitated(&self) -> bool {
+        self.stunned_ticks > 0 || self.in_shock || self.fainted || self.is_paralyzed()
+    }
+    
+    /// Lying helpless on the ground, where any part is in reach
+    pub fn is_prone(&self) -> bool {
+        self.pinned || self.in_shock || self.fainted || self.is_paralyzed()
+    }
+    
+    /// Made entirely of ethereal parts: bloodless, immune to venom and able
+    /// to drift through other actors
+    pub fn is_ethereal(&self) -> bool {
+        !self.parts.is_empty() && self.parts.iter().all(|p| p.is_ethereal())
+    }
+    
+    /// Made entirely of stone parts
+    pub fn is_stone(&self) -> bool {
+        !self.parts.is_empty() && self.parts.iter().all(|p| p.is_stone())
+    }
+    
+    /// No part of the body bleeds, so there's no blood for venom to spread
+    /// through or a stomach to turn
+    pub fn is_bloodless(&self) -> bool {
+        !self.parts.is_empty() && !self.parts.iter().any(|p| p.bleeds())
+    }
+    
+    /// Enough venom in the blood to lock up the muscles
+    pub fn is_paralyzed(&self) -> bool {
+        self.toxin >= PARALYSIS_TOXIN
+    }
+    
+    /// Stamina recovered each tick. Venom slows recovery, by up to three
+    /// quarters at the toxin ceiling.
+    pub fn stamina_regen(&self) -> u32 {
+        let regen = (self.max_stamina / 10).max(5);
+        regen * (100 - self.toxin.min(MAX_TOXIN) * 3 / 4) / 100
+    }
+    
+    /// Recover stamina for the tick. Flyers get no rest while aloft and pay
+    /// the flight upkeep instead; returns false once one is too tired to
+    /// stay up.
+    pub fn recover_stamina(&mut self) -> bool {
+        if !self.airborne {
+            self.stamina = (self.stamina + self.stamina_regen()).min(self.max_stamina);
+            return true;
+        }
+        let flight_cost = self.flight_stamina_cost();
+        if self.stamina < flight_cost {
+            return false;
+        }
+        self.stamina -= flight_cost;
+        true
+    }
+    
+    /// Percent of the actor's blood lost so far
+    pub fn blood_lost_percent(&self) -> u32 {
+        let lost = self.max_blood_ul.saturating_sub(self.blood_ul) as u64;
+        (lost * 100 / self.max_blood_ul.max(1) as u64) as u32
+    }
+    
+    /// Check if the actor still has working flight parts
+    pub fn can_fly(&self) -> bool {
+        self.has_part_with_tag("flight")
+    }
+    
+    /// Check if the actor has a part that can seize and hold an enemy:
+    /// grasping hands, or a part that provides a hold, like a jaw that
+    /// clamps down
+    pub fn can_grapple(&self, table: &[AttackTemplate]) -> bool {
+        self.has_part_with_tag("grasp")
+            || self.has_part_with_tag("manipulator")
+            || self.get_available_attacks(table).iter().any(Attack::is_hold)
+    }
+    
+    /// Drop out of the sky after losing flight mid-air, returning the fall damage taken
+    pub fn crash_land(&mut self) -> u32 {
+        self.airborne = false;
+        let fall_damage = (self.max_hp / 10).max(1);
+        self.take_damage(fall_damage);
+        fall_damage as u32
+    }
+    
     /// Remove a part from the actor (when severed or destroyed)
     pub fn remove_part(&mut self, part_id: &str) -> bool {
         if let Some(index) = self.parts.iter().position(|p| p.part_id == part_id) {
@@ -68,6 +258,37 @@ impl Actor {
         false
     }
     
+    /// Parts no longer connected to the body. A part stays on while any of
+    /// the parts it attaches to does; parts that attach to nothing anchor it.
+    pub fn disconnected_parts(&self) -> Vec<String> {
+        let mut connected: Vec<&str> = self
+            .parts
+            .iter()
+            .filter(|p| p.attachments.is_empty())
+            .map(|p| p.part_id.as_str())
+            .collect();
+        
+        loop {
+            let newly_connected: Vec<&str> = self
+                .parts
+                .iter()
+                .filter(|p| !connected.contains(&p.part_id.as_str()))
+                .filter(|p| p.attachments.iter().any(|a| connected.contains(&a.as_str())))
+                .map(|p| p.part_id.as_str())
+                .collect();
+            if newly_connected.is_empty() {
+                break;
+            }
+            connected.extend(newly_connected);
+        }
+        
+        self.parts
+            .iter()
+            .filter(|p| !connected.contains(&p.part_id.as_str()))
+            .map(|p| p.part_id.clone())
+            .collect()
+    }
+    
     /// Apply capability effects when a part is lost
     fn apply_part_loss_effects(&mut self, lost_part: &Part) {
         // Loss of vital parts = death
@@ -110,17 +331,28 @@ impl Actor {
         // Loss of weapon parts (handled by attack availability, not here)
     }
     
-    /// Get available attacks based on current parts
-    pub fn get_available_attacks(&self) -> Vec<Attack> {
+    /// Get available attacks based on current parts, derived from the
+    /// battle's attack table
+    pub fn get_available_attacks(&self, table: &[AttackTemplate]) -> Vec<Attack> {
         let mut attacks = Vec::new();
         
+        // Actors built by hand rather than from a species have no recorded
+        // body size; their current parts stand in for it
+        let body_hp = self.body_hp.max(self.parts.iter().map(|p| p.max_hp).sum());
+        
         for part in &self.parts {
-            if let Some(attack) = Attack::derive_from_tags(
+            for mut attack in Attack::derive_for_part(
+                table,
                 &part.part_id,
                 &part.display_name,
                 &part.tags,
                 part.hp,
+                Weapon::new(self.mass_g, part.max_hp, body_hp),
+                part.attacks_provided.as_deref(),
             ) {
+                // A mangled jaw or claw can't strike as hard
+                let function = part.wound_level().function_percent() as i32;
+                attack.damage.base_damage = (attack.damage.base_damage * function / 100).max(1);
                 attacks.push(attack);
             }
         }
@@ -1,5 +1,8 @@
 use rand::Rng;
 use crate::sim::{Actor, Action, Attack, CombatResolver};
+use crate::sim::attack::AttackTemplate;
+use crate::sim::combat::MELEE_RANGE_SQ;
+use crate::sim::grapple::{GRAPPLE_STAMINA_COST, STRUGGLE_STAMINA_COST};
 
 pub struct SimpleAI;
 
@@ -10,24 +13,58 @@ impl SimpleAI {
         actor: &Actor,
         allies: &[Actor],
         enemies: &[Actor],
+        table: &[AttackTemplate],
     ) -> Option<Action> {
         if !actor.is_alive() {
             return None;
         }
         
+        // Flyers land before they run out of stamina to stay aloft
+        if actor.airborne && actor.stamina < actor.max_stamina / 4 {
+            return Some(Action::land(actor.id));
+        }
+        
+        // Held actors fight the hold; pinned or panicking ones do nothing else
+        if actor.grappled_by.is_some() && actor.stamina >= STRUGGLE_STAMINA_COST
+            && (actor.pinned || actor.is_fleeing() || rng.gen_range(0..2) == 0)
+        {
+            return Some(Action::struggle(actor.id));
+        }
+        
+        // Grapplers work over whoever they are holding
+        if let Some(held_id) = actor.grappling {
+            let holding = enemies.iter().any(|e| e.id == held_id && e.is_alive());
+            if holding && actor.stamina >= GRAPPLE_STAMINA_COST {
+                let held_pinned = enemies.iter().any(|e| e.id == held_id && e.pinned);
+                let roll = rng.gen_range(0..100);
+                return Some(if !held_pinned && roll < 30 {
+                    Action::pin(actor.id)
+                } else if roll < 50 {
+                    Action::throw(actor.id)
+                } else {
+                    Action::tear(actor.id)
+                });
+            }
+        }
+        
         // Check for fleeing - override all other behavior
         if actor.is_fleeing() {
+            // Flyers escape into the air if they can
+            if Self::should_take_off(actor) {
+                return Some(Action::take_off(actor.id));
+            }
+            
             // Try to move away from nearest enemy
             let alive_enemies: Vec<&Actor> = enemies.iter().filter(|e| e.is_alive()).collect();
             if let Some(nearest) = alive_enemies.iter().min_by(|a, b| {
-                let dist_a = CombatResolver::distance(actor.x, actor.y, a.x, a.y);
-                let dist_b = CombatResolver::distance(actor.x, actor.y, b.x, b.y);
-                dist_a.partial_cmp(&dist_b).unwrap()
+                let dist_a = CombatResolver::distance_sq(actor.x, actor.y, a.x, a.y);
+                let dist_b = CombatResolver::distance_sq(actor.x, actor.y, b.x, b.y);
+                dist_a.cmp(&dist_b)
             }) {
                 // Move away from nearest enemy
                 let dx = (actor.x - nearest.x).signum();
                 let dy = (actor.y - nearest.y).signum();
-                return Some(Action::move_to(actor.id, actor.x + dx, actor.y + dy));
+                return Some(Self::step_to(actor, actor.x + dx, actor.y + dy));
             } else {
                 // No enemies - wait
                 return Some(Action::wait(actor.id));
@@ -37,8 +74,8 @@ impl SimpleAI {
         // Check for berserk - attack with bonus aggression
         let is_berserk = actor.is_berserk();
         
-        // Get available attacks
-        let attacks = actor.get_available_attacks();
+        // Get available strikes; holds are used through grappling instead
+        let attacks: Vec<Attack> = actor.get_available_attacks(table).into_iter().filter(|a| !a.is_hold()).collect();
         if attacks.is_empty() {
             return Some(Action::wait(actor.id));
         }
@@ -53,39 +90,51 @@ impl SimpleAI {
         let nearest_enemy = alive_enemies
             .iter()
             .min_by(|a, b| {
-                let dist_a = CombatResolver::distance(actor.x, actor.y, a.x, a.y);
-                let dist_b = CombatResolver::distance(actor.x, actor.y, b.x, b.y);
-                dist_a.partial_cmp(&dist_b).unwrap()
+                let dist_a = CombatResolver::distance_sq(actor.x, actor.y, a.x, a.y);
+                let dist_b = CombatResolver::distance_sq(actor.x, actor.y, b.x, b.y);
+                dist_a.cmp(&dist_b)
             })
             .copied();
         
         if let Some(enemy) = nearest_enemy {
-            let distance = CombatResolver::distance(actor.x, actor.y, enemy.x, enemy.y);
+            let distance_sq = CombatResolver::distance_sq(actor.x, actor.y, enemy.x, enemy.y);
+            
+            // Rested flyers take to the air to close in over obstacles and crowds
+            if distance_sq > MELEE_RANGE_SQ && Self::should_take_off(actor) {
+                return Some(Action::take_off(actor.id));
+            }
             
-            // If in melee range (adjacent orthogonally or diagonally)
-            // Orthogonal distance = 1.0, diagonal distance = sqrt(2) ≈ 1.414
-            if distance <= 1.5 {
-                // 15% chance to dodge/sidestep instead of attacking
-                if rng.gen_range(0..100) < 15 {
+            // If within reach of our longest attack. Melee attacks reach
+            // the adjacent cells: orthogonal distance² = 1, diagonal distance² = 2
+            let reach_sq = attacks.iter().map(Attack::range_sq).max().unwrap_or(MELEE_RANGE_SQ);
+            if distance_sq <= reach_sq {
+                // 15% chance to dodge/sidestep instead of attacking, for
+                // those that can move
+                if rng.gen_range(0..100) < 15 && actor.speed > 0 {
                     let dodge_moves = [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (1, 1), (-1, 1), (1, -1)];
                     let (dx, dy) = dodge_moves[rng.gen_range(0..dodge_moves.len())];
                     return Some(Action::move_to(actor.id, actor.x + dx, actor.y + dy));
                 }
                 
-                // Pick a random attack we can afford
+                // Grasping actors sometimes seize a lighter enemy instead of striking
+                if Self::can_seize(actor, enemy, table) && rng.gen_range(0..100) < 20 {
+                    return Some(Action::grapple(actor.id, enemy.id));
+                }
+                
+                // Pick a random attack we can afford that reaches the enemy
                 let affordable_attacks: Vec<&Attack> = attacks
                     .iter()
-                    .filter(|a| actor.stamina >= a.stamina_cost)
+                    .filter(|a| actor.stamina >= a.stamina_cost && distance_sq <= a.range_sq())
                     .collect();
                 
                 if !affordable_attacks.is_empty() {
                     // 20% chance to attack a different nearby enemy if available
                     let target = if alive_enemies.len() > 1 && rng.gen_range(0..100) < 20 {
-                        // Find other enemies in range
+                        // Find other adjacent enemies, which every attack reaches
                         let others_in_range: Vec<&&Actor> = alive_enemies.iter()
                             .filter(|e| {
-                                let d = CombatResolver::distance(actor.x, actor.y, e.x, e.y);
-                                d <= 1.5 && e.id != enemy.id
+                                let d = CombatResolver::distance_sq(actor.x, actor.y, e.x, e.y);
+                                d <= MELEE_RANGE_SQ && e.id != enemy.id
                             })
                             .collect();
                         if !others_in_range.is_empty() {
@@ -104,7 +153,10 @@ impl SimpleAI {
                         attack.attack_id.clone(),
                     ));
                 } else {
-                    // No stamina - step back to recover
+                    // No stamina - guard while catching breath, or step back to recover
+                    if actor.speed == 0 || rng.gen_range(0..2) == 0 {
+                        return Some(Action::defend(actor.id));
+                    }
                     let dx = (actor.x - enemy.x).signum();
                     let dy = (actor.y - enemy.y).signum();
                     return Some(Action::move_to(actor.id, actor.x + dx, actor.y + dy));
@@ -117,7 +169,7 @@ impl SimpleAI {
             
             // 10% chance to take a slightly different path (flank)
             let (final_dx, final_dy) = if rng.gen_range(0..100) < 10 {
-                if rng.gen_bool(0.5) {
+                if rng.gen_range(0..2) == 0 {
                     (dx, if dy == 0 { rng.gen_range(-1..=1) } else { dy })
                 } else {
                     (if dx == 0 { rng.gen_range(-1..=1) } else { dx }, dy)
@@ -126,10 +178,34 @@ impl SimpleAI {
                 (dx, dy)
             };
             
-            return Some(Action::move_to(actor.id, actor.x + final_dx, actor.y + final_dy));
+            return Some(Self::step_to(actor, actor.x + final_dx, actor.y + final_dy));
         }
         
         // No valid action
         Some(Action::wait(actor.id))
     }
+    
+    /// Step to a cell, or wait there, banking energy, if the actor can't move
+    fn step_to(actor: &Actor, x: i32, y: i32) -> Action {
+        if actor.speed == 0 {
+            Action::wait(actor.id)
+        } else {
+            Action::move_to(actor.id, x, y)
+        }
+    }
+    
+    /// Check if a free grasping actor could try to grab this enemy
+    fn can_seize(actor: &Actor, enemy: &Actor, table: &[AttackTemplate]) -> bool {
+        actor.can_grapple(table)
+            && actor.grappling.is_none()
+            && enemy.grappled_by.is_none()
+            && enemy.mass_g < actor.mass_g
+            && enemy.airborne == actor.airborne
+            && actor.stamina >= GRAPPLE_STAMINA_COST
+    }
+    
+    /// Grounded flyers take off once they have enough stamina to stay up a while
+    fn should_take_off(actor: &Actor) -> bool {
+        !actor.airborne && actor.can_fly() && actor.stamina >= actor.max_stamina * 3 / 5
+    }
 }
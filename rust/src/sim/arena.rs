@@ -0,0 +1,110 @@
+use serde::Serialize;
+
+/// Open cells wanted around each footprint cell so combatants have room to maneuver
+const CELLS_PER_FOOTPRINT: i32 = 6;
+
+/// Arena dimensions and spawn zone depth chosen for a matchup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ArenaSize {
+    pub width: i32,
+    pub height: i32,
+    /// How many columns in from each edge a team's spawn zone reaches
+    pub spawn_depth: i32,
+}
+
+impl ArenaSize {
+    pub const MIN_WIDTH: i32 = 16;
+    pub const MAX_WIDTH: i32 = 64;
+    pub const MIN_HEIGHT: i32 = 8;
+    pub const MAX_HEIGHT: i32 = 40;
+    
+    /// Choose an arena for two teams given the mass of every combatant.
+    /// Area scales with the total footprint of both armies (3:2 aspect), and
+    /// the spawn zone is deep enough to hold the larger army.
+    pub fn for_teams(team_a_masses: &[u32], team_b_masses: &[u32]) -> Self {
+        let team_a_footprint: i32 = team_a_masses.iter().map(|&m| footprint(m)).sum();
+        let team_b_footprint: i32 = team_b_masses.iter().map(|&m| footprint(m)).sum();
+        
+        let area = (team_a_footprint + team_b_footprint) * CELLS_PER_FOOTPRINT;
+        let height = isqrt(area * 2 / 3).clamp(Self::MIN_HEIGHT, Self::MAX_HEIGHT);
+        let mut width = (area / height).clamp(Self::MIN_WIDTH, Self::MAX_WIDTH);
+        
+        // Enough columns to fit the larger army, leaving a border row above and below
+        let rows = height - 2;
+        let larger_team = team_a_footprint.max(team_b_footprint);
+        let mut spawn_depth = ((larger_team + rows - 1) / rows + 1).max(2);
+        
+        // Keep a no-man's-land between the two spawn zones
+        let min_width = spawn_depth * 2 + 6;
+        if width < min_width {
+            width = min_width.min(Self::MAX_WIDTH);
+            spawn_depth = spawn_depth.min((width - 6) / 2);
+        }
+        
+        Self {
+            width,
+            height,
+            spawn_depth,
+        }
+    }
+}
+
+impl Default for ArenaSize {
+    fn default() -> Self {
+        Self {
+            width: 20,
+            height: 12,
+            spawn_depth: 3,
+        }
+    }
+}
+
+/// How much arena space a combatant of the given mass (in grams) takes up
+pub fn footprint(mass_g: u32) -> i32 {
+    if mass_g < 50_000 {
+        1
+    } else if mass_g < 300_000 {
+        2
+    } else {
+        3
+    }
+}
+
+/// Integer square root (floor)
+fn isqrt(n: i32) -> i32 {
+    if n <= 0 {
+        return 0;
+    }
+    let mut root = 0;
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_small_matchups_use_minimum_arena() {
+        let arena = ArenaSize::for_teams(&[2_500, 2_500, 2_500], &[30_000]);
+        assert_eq!(arena.width, ArenaSize::MIN_WIDTH);
+        assert_eq!(arena.height, ArenaSize::MIN_HEIGHT);
+    }
+    
+    #[test]
+    fn test_arena_grows_with_army_size_and_mass() {
+        let ants = ArenaSize::for_teams(&[1; 40], &[1; 40]);
+        let dragons = ArenaSize::for_teams(&[800_000; 40], &[800_000; 40]);
+        
+        assert!(ants.width * ants.height >= 80 * CELLS_PER_FOOTPRINT / 2);
+        assert!(dragons.width * dragons.height > ants.width * ants.height);
+        
+        // Each spawn zone must be able to hold its whole army
+        for (arena, per_team) in [(ants, 40), (dragons, 120)] {
+            assert!(arena.spawn_depth * (arena.height - 2) >= per_team);
+            assert!(arena.width >= arena.spawn_depth * 2 + 6);
+        }
+    }
+}
@@ -1,4 +1,31 @@
+use std::fs;
+use std::path::Path;
 use serde::{Deserialize, Serialize};
+use crate::sim::venom::VENOM_DOSE_PER_HP;
+
+/// Armor penetration of sharp weapons
+pub const SHARP_PENETRATION: i32 = 3;
+/// Armor penetration of blunt or crushing weapons
+pub const BLUNT_PENETRATION: i32 = 2;
+/// Bleed chance of sharp weapons, whatever their template says
+pub const SHARP_BLEED_CHANCE: u32 = 30;
+/// Stamina cost of every attack made with a strong part
+pub const STRONG_STAMINA_COST: u32 = 15;
+/// Percent damage of attacks made with a strong part
+pub const STRONG_DAMAGE_PERCENT: u32 = 125;
+/// Mass power (cube root of grams) behind each point of damage
+pub const MASS_POWER_PER_DAMAGE: u64 = 4;
+/// Percent damage of a weapon part that makes up none of the body
+pub const BASE_SIZE_PERCENT: u32 = 60;
+/// Percent of the body a weapon part can make up before growing it stops
+/// adding force
+pub const MAX_SIZE_SHARE: u32 = 40;
+/// Damage past this comes at half rate, so giants hit hard without
+/// taking parts off in one blow
+pub const DAMAGE_KNEE: i32 = 12;
+
+/// Attack table file, kept next to the species directory
+pub const ATTACK_TABLE_FILE: &str = "attacks.yaml";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Attack {
@@ -8,9 +35,12 @@ pub struct Attack {
     pub damage: DamageProfile,
     pub accuracy: u32,
     pub stamina_cost: u32,
+    /// Reach in cells, diagonals included
+    pub range: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AttackType {
     Peck,
     Bite,
@@ -21,130 +51,333 @@ pub enum AttackType {
     Bash,
     Gore,
     Claw,
+    /// Seizes the enemy in a grapple instead of striking
+    Hold,
+}
+
+/// How a weapon tag turns into an attack, from the attack table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttackTemplate {
+    pub id: String,
+    /// Weapon tag that grants the attack; untagged templates are only
+    /// reachable through `attacks_provided`
+    #[serde(default)]
+    pub tag: Option<String>,
+    pub attack_type: AttackType,
+    pub name: String,
+    /// Percent of the attacker's full force the attack delivers
+    #[serde(default)]
+    pub damage_percent: u32,
+    /// Floor on damage, so the smallest creatures still hurt each other
+    #[serde(default)]
+    pub min_damage: i32,
+    #[serde(default)]
+    pub accuracy: u32,
+    #[serde(default)]
+    pub stamina_cost: u32,
+    pub range: u32,
+    /// Share of the damage that cuts or punctures before sharpness; the
+    /// rest is blunt
+    #[serde(default)]
+    pub edge: Option<DamageShare>,
+    /// Bleed chance of unsharpened weapons
+    #[serde(default)]
+    pub bleed_chance: u32,
+}
+
+impl AttackTemplate {
+    /// Parse an attack table from YAML
+    pub fn parse_table(yaml: &str) -> Result<Vec<AttackTemplate>, String> {
+        serde_yaml::from_str(yaml).map_err(|e| format!("Failed to parse attack table YAML: {}", e))
+    }
+    
+    /// Load an attack table from a YAML file
+    pub fn load_table<P: AsRef<Path>>(path: P) -> Result<Vec<AttackTemplate>, String> {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read attack table: {}", e))?;
+        
+        Self::parse_table(&content)
+    }
+    
+    /// Look up a template by id
+    pub fn find<'a>(table: &'a [AttackTemplate], id: &str) -> Option<&'a AttackTemplate> {
+        table.iter().find(|t| t.id == id)
+    }
+}
+
+/// An attack a part lists for itself in place of the ones its tags would
+/// derive. Starts from a template; any field given replaces the template's.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttackOverride {
+    /// Id of the template to start from
+    pub attack: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub damage: Option<i32>,
+    #[serde(default)]
+    pub accuracy: Option<u32>,
+    #[serde(default)]
+    pub stamina_cost: Option<u32>,
+    #[serde(default)]
+    pub range: Option<u32>,
+}
+
+/// How a hit hurts: puncturing, cutting or crushing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DamageType {
+    Pierce,
+    Slash,
+    Blunt,
+}
+
+/// One damage type's portion of an attack, in percent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DamageShare {
+    pub damage_type: DamageType,
+    pub percent: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DamageProfile {
     pub base_damage: i32,
     pub armor_penetration: i32,
-    pub bleed_chance: f32,
-    pub is_sharp: bool,
-    pub is_blunt: bool,
+    /// Percent chance that a non-destroying hit starts a bleed
+    pub bleed_chance: u32,
+    /// Venom injected by a hit that gets through the armor
+    #[serde(default)]
+    pub venom: u32,
+    /// How the damage splits across types (percents sum to 100)
+    pub types: Vec<DamageShare>,
+}
+
+impl DamageProfile {
+    /// Percent of this attack's damage that is of the given type
+    pub fn share(&self, damage_type: DamageType) -> u32 {
+        self.types
+            .iter()
+            .filter(|s| s.damage_type == damage_type)
+            .map(|s| s.percent)
+            .sum()
+    }
+    
+    /// Attacks that are at least half edged cut destroyed parts clean off
+    pub fn can_sever(&self) -> bool {
+        self.share(DamageType::Pierce) + self.share(DamageType::Slash) >= 50
+    }
 }
 
 impl Attack {
-    /// Derive an attack from a part's tags
+    /// Derive a part's attacks: the ones it lists in `attacks_provided`, or
+    /// else one for every weapon tag it carries
+    pub fn derive_for_part(
+        table: &[AttackTemplate],
+        part_id: &str,
+        part_name: &str,
+        tags: &[String],
+        hp: i32,
+        weapon: Weapon,
+        provided: Option<&[AttackOverride]>,
+    ) -> Vec<Self> {
+        match provided {
+            // Unknown template ids are rejected when the species is validated
+            Some(overrides) => overrides
+                .iter()
+                .filter_map(|o| {
+                    let template = AttackTemplate::find(table, &o.attack)?;
+                    Some(Self::from_template(template, part_id, part_name, tags, hp, weapon).with_override(o, part_name))
+                })
+                .collect(),
+            None => Self::derive_from_tags(table, part_id, part_name, tags, hp, weapon),
+        }
+    }
+    
+    /// Derive attacks from a part's weapon tags, one per matching template
     pub fn derive_from_tags(
+        table: &[AttackTemplate],
         part_id: &str,
         part_name: &str,
         tags: &[String],
         hp: i32,
-    ) -> Option<Self> {
-        let has_peck = tags.iter().any(|t| t == "peck_weapon");
-        let has_bite = tags.iter().any(|t| t == "bite_weapon");
-        let has_scratch = tags.iter().any(|t| t == "scratch_weapon");
-        let has_sting = tags.iter().any(|t| t == "sting_weapon");
-        let has_bash = tags.iter().any(|t| t == "bash_weapon");
-        let has_gore = tags.iter().any(|t| t == "gore_weapon");
-        let has_claw = tags.iter().any(|t| t == "claw_weapon");
-        let has_kick = tags.iter().any(|t| t == "kick_weapon");
-        
+        weapon: Weapon,
+    ) -> Vec<Self> {
+        table
+            .iter()
+            .filter(|template| template.tag.as_ref().is_some_and(|tag| tags.contains(tag)))
+            .map(|template| Self::from_template(template, part_id, part_name, tags, hp, weapon))
+            .collect()
+    }
+    
+    /// Build a part's attack from a template. Damage comes from the body
+    /// behind the weapon; the part's modifier tags (`sharp`, `blunt`,
+    /// `crushing`, `strong`, `venom`) adjust the result.
+    pub fn from_template(
+        template: &AttackTemplate,
+        part_id: &str,
+        part_name: &str,
+        tags: &[String],
+        hp: i32,
+        weapon: Weapon,
+    ) -> Self {
         let has_sharp = tags.iter().any(|t| t == "sharp");
         let has_blunt = tags.iter().any(|t| t == "blunt");
         let has_strong = tags.iter().any(|t| t == "strong");
         let has_crushing = tags.iter().any(|t| t == "crushing");
+        let has_venom = tags.iter().any(|t| t == "venom");
         
-        let (attack_type, base_name) = if has_peck {
-            (AttackType::Peck, "Peck")
-        } else if has_bite {
-            (AttackType::Bite, "Bite")
-        } else if has_scratch {
-            (AttackType::Scratch, "Scratch")
-        } else if has_claw {
-            (AttackType::Claw, "Claw")
-        } else if has_sting {
-            (AttackType::Sting, "Sting")
-        } else if has_bash {
-            (AttackType::Bash, "Bash")
-        } else if has_gore {
-            (AttackType::Gore, "Gore")
-        } else if has_kick {
-            (AttackType::Kick, "Kick")
-        } else {
-            return None; // Not a weapon part
-        };
+        let strength = if has_strong { STRONG_DAMAGE_PERCENT } else { 100 };
+        let base_damage = weapon.damage(template.damage_percent, strength).max(template.min_damage);
         
-        // Calculate damage based on part HP and tags
-        let base_damage = match attack_type {
-            AttackType::Peck => (hp / 4).max(2),
-            AttackType::Bite => (hp / 3).max(3),
-            AttackType::Scratch => (hp / 5).max(1),
-            AttackType::Claw => (hp / 4).max(2),
-            AttackType::Sting => (hp / 4).max(2),
-            AttackType::Bash => (hp / 3).max(4),
-            AttackType::Gore => (hp / 3).max(5),
-            AttackType::Kick => (hp / 4).max(3),
-            _ => hp / 4,
-        };
+        let types = Self::damage_mix(template.edge, has_sharp, has_crushing);
         
         let armor_penetration = if has_sharp {
-            3
+            SHARP_PENETRATION
         } else if has_blunt || has_crushing {
-            2
+            BLUNT_PENETRATION
         } else {
             0
         };
         
-        let bleed_chance = if has_sharp {
-            0.3
-        } else if matches!(attack_type, AttackType::Bite | AttackType::Claw | AttackType::Gore) {
-            0.2
-        } else {
-            0.05
-        };
+        let bleed_chance = if has_sharp { SHARP_BLEED_CHANCE } else { template.bleed_chance };
         
-        let accuracy = match attack_type {
-            AttackType::Peck => 75,
-            AttackType::Bite => 65,
-            AttackType::Scratch => 70,
-            AttackType::Claw => 70,
-            AttackType::Sting => 60,
-            AttackType::Bash => 75,
-            AttackType::Gore => 60,
-            AttackType::Kick => 65,
-            _ => 70,
-        };
+        let venom = if has_venom { hp.max(0) as u32 * VENOM_DOSE_PER_HP } else { 0 };
         
-        let stamina_cost = if has_strong {
-            15
-        } else {
-            match attack_type {
-                AttackType::Bite => 12,
-                AttackType::Bash => 14,
-                AttackType::Gore => 14,
-                AttackType::Kick => 12,
-                AttackType::Peck => 8,
-                AttackType::Scratch => 6,
-                AttackType::Claw => 8,
-                AttackType::Sting => 10,
-                _ => 10,
-            }
-        };
+        let stamina_cost = if has_strong { STRONG_STAMINA_COST } else { template.stamina_cost };
         
-        Some(Attack {
-            attack_id: format!("{}_attack", part_id),
-            display_name: format!("{} with {}", base_name, part_name),
-            attack_type,
+        Attack {
+            attack_id: format!("{}_{}", part_id, template.id),
+            display_name: format!("{} with {}", template.name, part_name),
+            attack_type: template.attack_type.clone(),
             damage: DamageProfile {
                 base_damage,
                 armor_penetration,
                 bleed_chance,
-                is_sharp: has_sharp,
-                is_blunt: has_blunt || has_crushing,
+                venom,
+                types,
             },
-            accuracy,
+            accuracy: template.accuracy,
             stamina_cost,
-        })
+            range: template.range,
+        }
+    }
+    
+    /// Apply a part's own tuning on top of the template
+    fn with_override(mut self, o: &AttackOverride, part_name: &str) -> Self {
+        if let Some(name) = &o.name {
+            self.display_name = format!("{} with {}", name, part_name);
+        }
+        if let Some(damage) = o.damage {
+            self.damage.base_damage = damage;
+        }
+        if let Some(accuracy) = o.accuracy {
+            self.accuracy = accuracy;
+        }
+        if let Some(stamina_cost) = o.stamina_cost {
+            self.stamina_cost = stamina_cost;
+        }
+        if let Some(range) = o.range {
+            self.range = range;
+        }
+        self
+    }
+    
+    /// Whether this seizes the enemy rather than striking it
+    pub fn is_hold(&self) -> bool {
+        matches!(self.attack_type, AttackType::Hold)
+    }
+    
+    /// Squared reach of the attack, for `CombatResolver::is_in_range`.
+    /// Covers the diagonal cells at the attack's range.
+    pub fn range_sq(&self) -> i32 {
+        let range = self.range as i32;
+        2 * range * range
+    }
+}
+
+/// The body behind a weapon part, which decides how hard it hits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Weapon {
+    /// The attacker's mass in grams
+    pub mass_g: u32,
+    /// Percent of the attacker's body the weapon part makes up, by health
+    pub size_share: u32,
+}
+
+impl Weapon {
+    /// A weapon part of `part_hp` on a body of `body_hp` in total
+    pub fn new(mass_g: u32, part_hp: i32, body_hp: i32) -> Self {
+        Self {
+            mass_g,
+            size_share: (part_hp.max(0) * 100 / body_hp.max(1)) as u32,
+        }
+    }
+    
+    /// Striking power from mass: the cube root of the weight in grams, so
+    /// a creature eight times heavier hits only twice as hard
+    pub fn mass_power(mass_g: u32) -> u32 {
+        // Binary search for the largest root whose cube fits; 2048 cubed
+        // is past u32::MAX and still well inside u64
+        let mass = mass_g as u64;
+        let (mut low, mut high) = (0u64, 2048u64);
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if mid * mid * mid <= mass {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low as u32
+    }
+    
+    /// Percent damage from the weapon's size: bigger parts put more of the
+    /// body's weight behind the blow
+    pub fn size_percent(&self) -> u32 {
+        BASE_SIZE_PERCENT + self.size_share.min(MAX_SIZE_SHARE) * 2
+    }
+    
+    /// Damage of a blow delivering `damage_percent` of the attacker's full
+    /// force at `strength` percent, with diminishing returns past the knee
+    pub fn damage(&self, damage_percent: u32, strength: u32) -> i32 {
+        let force = Self::mass_power(self.mass_g) as u64
+            * damage_percent as u64
+            * self.size_percent() as u64
+            * strength as u64;
+        let raw = (force / (100 * 100 * 100 * MASS_POWER_PER_DAMAGE)) as i32;
+        if raw > DAMAGE_KNEE {
+            DAMAGE_KNEE + (raw - DAMAGE_KNEE) / 2
+        } else {
+            raw
+        }
+    }
+}
+
+impl Attack {
+    /// Split of damage types for an attack. Blunt-edged (unsharpened) weapons
+    /// keep only 40% of their edged damage, and crushing jaws trade some of
+    /// their bite for crush.
+    fn damage_mix(edge: Option<DamageShare>, sharp: bool, crushing: bool) -> Vec<DamageShare> {
+        let (edge, mut edged) = match edge {
+            Some(share) => (share.damage_type, share.percent),
+            None => (DamageType::Pierce, 0),
+        };
+        
+        if !sharp {
+            edged = edged * 2 / 5;
+        }
+        if crushing {
+            edged = edged.saturating_sub(10);
+        }
+        
+        let mut types = Vec::new();
+        if edged > 0 {
+            types.push(DamageShare { damage_type: edge, percent: edged });
+        }
+        if edged < 100 {
+            types.push(DamageShare { damage_type: DamageType::Blunt, percent: 100 - edged });
+        }
+        types
     }
 }
 
@@ -160,6 +393,7 @@ impl AttackType {
             AttackType::Kick => "kick",
             AttackType::Bash => "bash",
             AttackType::Gore => "gore",
+            AttackType::Hold => "hold",
         }
     }
 }
@@ -176,3 +410,59 @@ impl Attack {
         )
     }
 }
+
+#[cfg(test)]
+impl AttackTemplate {
+    /// Load the shared attack table for tests that derive attacks
+    pub(crate) fn load_test_table() -> Vec<AttackTemplate> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../data").join(ATTACK_TABLE_FILE);
+        Self::load_table(path).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_bad_attack_tables_are_errors() {
+        assert!(AttackTemplate::parse_table("- id: peck\n  range: far").is_err());
+        assert!(AttackTemplate::parse_table("not a list").is_err());
+        assert!(!AttackTemplate::load_test_table().is_empty());
+    }
+    
+    #[test]
+    fn test_mass_power_is_a_whole_cube_root() {
+        assert_eq!(Weapon::mass_power(8_000), 20);
+        assert_eq!(Weapon::mass_power(7_999), 19);
+        assert_eq!(Weapon::mass_power(1), 1);
+        assert_eq!(Weapon::mass_power(0), 0);
+        assert_eq!(Weapon::mass_power(u32::MAX), 1625);
+    }
+    
+    #[test]
+    fn test_heavier_bigger_and_stronger_weapons_hit_harder() {
+        let cat = Weapon::new(4_500, 10, 100);
+        let tiger = Weapon::new(180_000, 10, 100);
+        
+        assert!(cat.damage(100, 100) < tiger.damage(100, 100));
+        assert!(Weapon::new(180_000, 30, 100).damage(100, 100) > tiger.damage(100, 100));
+        assert!(tiger.damage(100, STRONG_DAMAGE_PERCENT) > tiger.damage(100, 100));
+        assert!(tiger.damage(50, 100) < tiger.damage(100, 100));
+    }
+    
+    #[test]
+    fn test_giants_get_diminishing_returns() {
+        // Forty times the mass of a tiger is far less than forty times the damage
+        let tiger = Weapon::new(180_000, 20, 100).damage(100, 100);
+        let titan = Weapon::new(7_200_000, 20, 100).damage(100, 100);
+        assert!(titan > tiger);
+        assert!(titan < tiger * 3);
+        
+        // Past the knee, each extra point of force adds only half a point
+        let knee = Weapon::new(1_000_000, 20, 100);
+        let raw = Weapon::mass_power(knee.mass_g) as i32 * knee.size_percent() as i32 / 100 / MASS_POWER_PER_DAMAGE as i32;
+        assert!(raw > DAMAGE_KNEE);
+        assert_eq!(knee.damage(100, 100), DAMAGE_KNEE + (raw - DAMAGE_KNEE) / 2);
+    }
+}
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use crate::sim::Actor;
+use crate::sim::pain::PainResolver;
+use crate::events::BattleEvent;
+
+/// What an aura does to the actors inside it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuraKind {
+    /// Drains enemy morale
+    Fear,
+    /// Restores allies' morale
+    Rally,
+    /// Scorches everyone nearby, friend or foe
+    Heat,
+}
+
+/// An aura declared in species YAML
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Aura {
+    pub kind: AuraKind,
+    /// Reach in cells; a diagonal step counts as one cell
+    pub radius: i32,
+    /// Morale drained or restored, or heat pain inflicted, every tick
+    pub strength: u32,
+    /// Part tag that grants the aura. It fades once no part carrying the
+    /// tag is left, and actors that carry the tag themselves are immune.
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+impl Aura {
+    /// Whether the aura is still radiating from this actor
+    pub fn is_active(&self, source: &Actor) -> bool {
+        source.is_alive()
+            && match &self.tag {
+                Some(tag) => source.has_part_with_tag(tag),
+                None => true,
+            }
+    }
+    
+    /// Whether the aura reaches and works on this actor
+    pub fn affects(&self, source: &Actor, target: &Actor) -> bool {
+        if target.id == source.id || !target.is_alive() {
+            return false;
+        }
+        if self.tag.as_ref().is_some_and(|tag| target.has_part_with_tag(tag)) {
+            return false;
+        }
+        let sides = match self.kind {
+            AuraKind::Fear => target.team != source.team,
+            AuraKind::Rally => target.team == source.team,
+            AuraKind::Heat => true,
+        };
+        sides && (target.x - source.x).abs().max((target.y - source.y).abs()) <= self.radius
+    }
+}
+
+pub struct AuraResolver;
+
+impl AuraResolver {
+    /// Apply one tick of an aura to an actor inside it
+    pub fn apply(aura: &Aura, target: &mut Actor) -> Vec<BattleEvent> {
+        match aura.kind {
+            AuraKind::Fear => {
+                target.reduce_morale(aura.strength);
+                Vec::new()
+            }
+            AuraKind::Rally => {
+                target.restore_morale(aura.strength);
+                Vec::new()
+            }
+            AuraKind::Heat => {
+                // Heat saps stamina and hurts without wounding any one part
+                target.stamina = target.stamina.saturating_sub(aura.strength * 2);
+                PainResolver::inflict(target, aura.strength as i32)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anatomy::part::Part;
+    
+    fn create_actor(id: u32, team: u8, x: i32, y: i32) -> Actor {
+        let mut actor = Actor::new(id, "test".to_string(), 'T', "white".to_string(), team, x, y);
+        actor.parts.push(Part::new("torso".to_string(), "Torso".to_string()));
+        actor
+    }
+    
+    fn aura(kind: AuraKind, tag: Option<&str>) -> Aura {
+        Aura { kind, radius: 2, strength: 3, tag: tag.map(|t| t.to_string()) }
+    }
+    
+    #[test]
+    fn test_auras_pick_their_side_and_reach() {
+        let demon = create_actor(1, 0, 5, 5);
+        let ally = create_actor(2, 0, 6, 6);
+        let enemy = create_actor(3, 1, 7, 5);
+        let far_enemy = create_actor(4, 1, 8, 5);
+        
+        let fear = aura(AuraKind::Fear, None);
+        assert!(fear.affects(&demon, &enemy));
+        assert!(!fear.affects(&demon, &far_enemy));
+        assert!(!fear.affects(&demon, &ally));
+        assert!(!fear.affects(&demon, &demon));
+        
+        let rally = aura(AuraKind::Rally, None);
+        assert!(rally.affects(&demon, &ally) && !rally.affects(&demon, &enemy));
+        
+        let heat = aura(AuraKind::Heat, None);
+        assert!(heat.affects(&demon, &ally) && heat.affects(&demon, &enemy));
+    }
+    
+    #[test]
+    fn test_tagged_auras_fade_with_their_part_and_spare_their_kind() {
+        let mut lava = create_actor(1, 0, 0, 0);
+        lava.parts[0].tags.push("burning".to_string());
+        let mut other_lava = create_actor(2, 1, 1, 0);
+        other_lava.parts[0].tags.push("burning".to_string());
+        let mut wolf = create_actor(3, 1, 0, 1);
+        
+        let heat = aura(AuraKind::Heat, Some("burning"));
+        assert!(heat.is_active(&lava));
+        assert!(!heat.affects(&lava, &other_lava), "Burning creatures don't burn each other");
+        assert!(heat.affects(&lava, &wolf));
+        
+        AuraResolver::apply(&heat, &mut wolf);
+        assert_eq!(wolf.stamina, 94);
+        
+        lava.parts[0].tags.clear();
+        assert!(!heat.is_active(&lava));
+    }
+    
+    #[test]
+    fn test_fear_drains_and_rally_restores_morale() {
+        let mut actor = create_actor(1, 0, 0, 0);
+        actor.morale = 50;
+        
+        AuraResolver::apply(&aura(AuraKind::Fear, None), &mut actor);
+        assert_eq!(actor.morale, 47);
+        AuraResolver::apply(&aura(AuraKind::Rally, None), &mut actor);
+        assert_eq!(actor.morale, 50);
+    }
+}
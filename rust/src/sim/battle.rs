@@ -2,16 +2,25 @@ use rand::rngs::SmallRng;
 use rand_seeder::Seeder;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 use crate::events::BattleEvent;
-use crate::species::{Species, SpeciesLoader};
-use crate::anatomy::part::Part;
+use crate::species::{Species, SpeciesLoader, SpeciesValidator};
+use crate::anatomy::part::{Part, Resistances};
 use crate::variation::VariationGenerator;
-use super::actor::Actor;
-use super::grid::Grid;
+use super::actor::{Actor, BLOOD_UL_PER_G};
+use super::grid::{DecalKind, Grid};
 use super::action::Action;
-use super::combat::CombatResolver;
+use super::combat::{CombatResolver, MELEE_RANGE_SQ};
 use super::ai::SimpleAI;
+use super::spawn::{Formation, SpawnPlanner, SpawnSide};
+use super::arena::ArenaSize;
+use super::grapple::{GrappleResolver, GRAPPLE_STAMINA_COST};
+use super::pain::PainResolver;
+use super::venom::VenomResolver;
+use super::nausea::NauseaResolver;
+use super::aura::{AuraKind, AuraResolver};
+use super::attack::{AttackTemplate, ATTACK_TABLE_FILE};
 
 #[derive(Debug, Serialize)]
 pub struct Battle {
@@ -20,9 +29,19 @@ pub struct Battle {
     rng: SmallRng,
     #[serde(skip)]
     species_loader: SpeciesLoader,
+    /// Templates every actor's attacks are derived from
+    #[serde(skip)]
+    attack_table: Vec<AttackTemplate>,
+    /// Arena dimensions chosen at init, available before the first tick
+    arena: ArenaSize,
     grid: Grid,
     team_a: Vec<Actor>,
     team_b: Vec<Actor>,
+    /// Combatants still waiting to arrive, in arrival order
+    reinforcements: Vec<Reinforcement>,
+    /// Who is inside whose aura as of the last tick: (source, target, kind)
+    #[serde(skip)]
+    aura_contacts: Vec<(u32, u32, AuraKind)>,
     tick_count: u64,
     finished: bool,
     winner: Option<u8>, // 0 = team A, 1 = team B
@@ -36,9 +55,13 @@ impl Battle {
             seed,
             rng,
             species_loader: SpeciesLoader::new(),
+            attack_table: Vec::new(),
+            arena: ArenaSize::default(),
             grid: Grid::new(20, 12), // Will be resized in init_with_species based on actor count
             team_a: Vec::new(),
             team_b: Vec::new(),
+            reinforcements: Vec::new(),
+            aura_contacts: Vec::new(),
             tick_count: 0,
             finished: false,
             winner: None,
@@ -47,102 +70,142 @@ impl Battle {
     
     /// Initialize battle with species data from YAML files
     pub fn init_with_species(&mut self, species_dir: &str, team_a_json: &str, team_b_json: &str) -> Result<(), String> {
+        // The attack table sits next to the species directory
+        let species_path = std::path::Path::new(species_dir);
+        let data_dir = species_path.parent().unwrap_or(species_path);
+        self.attack_table = AttackTemplate::load_table(data_dir.join(ATTACK_TABLE_FILE))?;
+        
         // Load species from directory
         self.species_loader.load_from_directory(species_dir)?;
         
         // Parse team composition
-        let team_a_data: Vec<TeamMemberData> = serde_json::from_str(team_a_json)
-            .map_err(|e| format!("Failed to parse team A: {}", e))?;
-        let team_b_data: Vec<TeamMemberData> = serde_json::from_str(team_b_json)
-            .map_err(|e| format!("Failed to parse team B: {}", e))?;
+        let team_a = parse_team(team_a_json, "A")?;
+        let team_b = parse_team(team_b_json, "B")?;
         
-        // Calculate appropriate grid size based on actor count
-        let total_actors = team_a_data.len() + team_b_data.len();
-        let max_team = team_a_data.len().max(team_b_data.len());
-        // Height: enough rows for the larger team with spacing, capped at 16
-        let grid_height = ((max_team * 2) + 2).max(8).min(16) as i32;
-        // Width: smaller ratio for more compact arenas, around 20-24 width
-        let grid_width = (grid_height + 8).max(16).min(24) as i32;
-        self.grid = Grid::new(grid_width, grid_height);
+        // Only the species taking part are checked against the attack table
+        self.validate_team_species(&team_a, &team_b)?;
         
-        // Spawn team A on the left side (centered vertically with random offset)
-        let team_a_center_y = self.grid.height() / 2;
-        let team_a_spread = (team_a_data.len() as i32).min(self.grid.height() - 2);
-        let team_a_start_y = team_a_center_y - team_a_spread / 2;
+        // Size the arena from how many combatants there are and how big they are
+        let team_a_masses = self.team_masses(&team_a)?;
+        let team_b_masses = self.team_masses(&team_b)?;
+        self.arena = ArenaSize::for_teams(&team_a_masses, &team_b_masses);
+        self.grid = Grid::new(self.arena.width, self.arena.height);
         
-        for (idx, data) in team_a_data.iter().enumerate() {
-            let species = self.species_loader.get_species(&data.species_id)
-                .ok_or_else(|| format!("Species '{}' not found", data.species_id))?;
-            
-            // Space actors vertically with small random offset
-            let base_y = team_a_start_y + (idx as i32 * team_a_spread / team_a_data.len().max(1) as i32);
-            let random_offset = (self.rng.gen_range(0..5) as i32) - 2; // -2 to +2
-            let y = (base_y + random_offset).clamp(1, self.grid.height() - 2);
-            
-            // Slight random x offset (1-3 from left edge)
-            let x = 2 + (self.rng.gen_range(0..2) as i32);
-            
-            let mut actor = self.create_actor_from_species(idx as u32, species, 0, x, y);
+        // Only members present from the start are placed now
+        let team_a_initial: Vec<&TeamMemberData> = team_a.members.iter().filter(|m| m.arrive_tick == 0).collect();
+        let team_b_initial: Vec<&TeamMemberData> = team_b.members.iter().filter(|m| m.arrive_tick == 0).collect();
+        
+        // Plan spawn cells for both teams so nobody shares a cell
+        let (team_a_cells, team_b_cells) = {
+            let mut planner = SpawnPlanner::new(&self.grid, self.arena.spawn_depth);
             
-            // Apply variation - either specified or auto-generated
-            if let Some(variation) = &data.variation {
-                self.apply_variation(&mut actor, variation);
-            } else {
-                // Auto-generate variation
-                self.apply_auto_variation(&mut actor);
+            // Explicit positions are claimed first so formations flow around them
+            for member in team_a_initial.iter().chain(team_b_initial.iter()) {
+                if let Some(pos) = &member.position {
+                    planner.reserve(pos.x, pos.y)?;
+                }
             }
             
-            self.team_a.push(actor);
+            let team_a_formation = team_a.formation.unwrap_or_default();
+            let team_b_formation = team_b.formation.unwrap_or_default();
+            let team_a_cells = plan_team_cells(&mut planner, &mut self.rng, &team_a_initial, team_a_formation, SpawnSide::Left)?;
+            let team_b_cells = plan_team_cells(&mut planner, &mut self.rng, &team_b_initial, team_b_formation, SpawnSide::Right)?;
+            (team_a_cells, team_b_cells)
+        };
+        
+        // Team A ids come first, then team B, counting late arrivals too
+        let teams = [
+            (0u8, &team_a, team_a_cells, SpawnSide::Left, 0),
+            (1u8, &team_b, team_b_cells, SpawnSide::Right, team_a.members.len()),
+        ];
+        for (team, composition, cells, home_side, id_offset) in teams {
+            let mut cells = cells.into_iter();
+            for (idx, data) in composition.members.iter().enumerate() {
+                let id = (id_offset + idx) as u32;
+                
+                if data.arrive_tick == 0 {
+                    let (x, y) = cells.next().ok_or_else(|| "Missing spawn cell".to_string())?;
+                    let actor = self.create_member(id, team, data, x, y)?;
+                    if team == 0 {
+                        self.team_a.push(actor);
+                    } else {
+                        self.team_b.push(actor);
+                    }
+                } else {
+                    // Late arrivals are rolled now and held back until their tick
+                    let actor = self.create_member(id, team, data, 0, 0)?;
+                    self.reinforcements.push(Reinforcement {
+                        arrive_tick: data.arrive_tick,
+                        edge: data.edge.unwrap_or(home_side),
+                        formation: data.formation.or(composition.formation).unwrap_or_default(),
+                        position: data.position.as_ref().map(|p| (p.x, p.y)),
+                        actor,
+                    });
+                }
+            }
         }
         
-        // Spawn team B on the right side (centered vertically with random offset)
-        let team_b_center_y = self.grid.height() / 2;
-        let team_b_spread = (team_b_data.len() as i32).min(self.grid.height() - 2);
-        let team_b_start_y = team_b_center_y - team_b_spread / 2;
+        // Keep the queue in arrival order (stable, so ties stay in id order)
+        self.reinforcements.sort_by_key(|r| r.arrive_tick);
         
-        for (idx, data) in team_b_data.iter().enumerate() {
-            let species = self.species_loader.get_species(&data.species_id)
-                .ok_or_else(|| format!("Species '{}' not found", data.species_id))?;
-            
-            // Space actors vertically with small random offset
-            let base_y = team_b_start_y + (idx as i32 * team_b_spread / team_b_data.len().max(1) as i32);
-            let random_offset = (self.rng.gen_range(0..5) as i32) - 2; // -2 to +2
-            let y = (base_y + random_offset).clamp(1, self.grid.height() - 2);
-            
-            // Slight random x offset (2-4 from right edge)
-            let x = self.grid.width() - 3 - (self.rng.gen_range(0..2) as i32);
-            
-            let mut actor = self.create_actor_from_species(
-                (team_a_data.len() + idx) as u32,
-                species,
-                1,
-                x,
-                y
-            );
-            
-            // Apply variation - either specified or auto-generated
-            if let Some(variation) = &data.variation {
-                self.apply_variation(&mut actor, variation);
-            } else {
-                // Auto-generate variation
-                self.apply_auto_variation(&mut actor);
-            }
-            
-            self.team_b.push(actor);
+        Ok(())
+    }
+    
+    /// Build one team member from its species, with specified or rolled variation
+    fn create_member(&mut self, id: u32, team: u8, data: &TeamMemberData, x: i32, y: i32) -> Result<Actor, String> {
+        let species = self.species_loader.get_species(&data.species_id)
+            .ok_or_else(|| format!("Species '{}' not found", data.species_id))?;
+        
+        let mut actor = self.create_actor_from_species(id, species, team, x, y);
+        
+        // Apply variation - either specified or auto-generated
+        if let Some(variation) = &data.variation {
+            self.apply_variation(&mut actor, variation);
+        } else {
+            // Auto-generate variation
+            self.apply_auto_variation(&mut actor);
         }
         
+        Ok(actor)
+    }
+    
+    /// Look up the species mass of every member of a team
+    fn validate_team_species(&self, team_a: &TeamComposition, team_b: &TeamComposition) -> Result<(), String> {
+        let validator = SpeciesValidator::new();
+        let mut checked = HashSet::new();
+        for member in team_a.members.iter().chain(team_b.members.iter()) {
+            if !checked.insert(member.species_id.as_str()) {
+                continue;
+            }
+            let species = self.species_loader.get_species(&member.species_id)
+                .ok_or_else(|| format!("Species '{}' not found", member.species_id))?;
+            validator
+                .validate(species, &self.attack_table)
+                .map_err(|errors| format!("Invalid species '{}': {}", species.id, errors.join("; ")))?;
+        }
         Ok(())
     }
     
+    fn team_masses(&self, team: &TeamComposition) -> Result<Vec<u32>, String> {
+        team.members
+            .iter()
+            .map(|m| {
+                self.species_loader.get_species(&m.species_id)
+                    .map(|s| s.base_stats.mass_g())
+                    .ok_or_else(|| format!("Species '{}' not found", m.species_id))
+            })
+            .collect()
+    }
+    
     /// Apply auto-generated variation to an actor
     fn apply_auto_variation(&mut self, actor: &mut Actor) {
         // Generate stat variation
         let (hp_mult, speed_mult, stamina_mult) = VariationGenerator::generate_stat_variation(&mut self.rng);
         
-        actor.max_hp = ((actor.max_hp as f32) * hp_mult) as i32;
+        actor.max_hp = scale_percent(actor.max_hp, hp_mult);
         actor.hp = actor.max_hp;
-        actor.speed = ((actor.speed as f32) * speed_mult) as u32;
-        actor.max_stamina = ((actor.max_stamina as f32) * stamina_mult) as u32;
+        actor.speed = scale_percent(actor.speed as i32, speed_mult) as u32;
+        actor.max_stamina = scale_percent(actor.max_stamina as i32, stamina_mult) as u32;
         actor.stamina = actor.max_stamina;
         
         // Generate pre-existing injuries
@@ -183,13 +246,17 @@ impl Battle {
                     part_id: part_instance_id,
                     display_name: part_def.display_name.clone(),
                     count: 1,
-                    attachments: part_def.attachments.clone(),
+                    attachments: attachment_instances(species, &part_def.attachments, part_def.count, i),
                     tags: part_def.tags.clone(),
                     hp: part_def.hp,
                     max_hp: part_def.hp,
                     armor: part_def.armor,
-                    bleed_rate: 0, // Start with no bleeding - bleed_rate increases when wounded
+                    bleed_rate: 0, // Start with no bleeding - wounds open bleeds at the part's base rate
+                    base_bleed_rate: part_def.bleed_rate,
                     hit_weight: part_def.hit_weight,
+                    resistances: part_def.resistances.unwrap_or_else(|| Resistances::from_tags(&part_def.tags)),
+                    zone: part_def.zone,
+                    attacks_provided: part_def.attacks_provided.clone(),
                 };
                 
                 total_hp += part_def.hp;
@@ -197,7 +264,14 @@ impl Battle {
             }
         }
         
+        actor.mass_g = species.base_stats.mass_g();
+        actor.max_blood_ul = actor.mass_g.saturating_mul(BLOOD_UL_PER_G);
+        actor.blood_ul = actor.max_blood_ul;
+        actor.pain_tolerance = species.base_stats.pain_tolerance;
+        actor.auras = species.auras.clone();
+        
         // Set actor HP to sum of all part HP
+        actor.body_hp = total_hp;
         actor.max_hp = total_hp.max(1); // Ensure at least 1 HP
         actor.hp = actor.max_hp;
         
@@ -208,16 +282,16 @@ impl Battle {
     fn apply_variation(&self, actor: &mut Actor, variation: &IndividualVariation) {
         // Apply stat multipliers
         if let Some(hp_mult) = variation.hp_multiplier {
-            actor.max_hp = ((actor.max_hp as f32) * hp_mult) as i32;
+            actor.max_hp = scale_percent(actor.max_hp, multiplier_to_percent(hp_mult));
             actor.hp = actor.max_hp;
         }
         
         if let Some(speed_mult) = variation.speed_multiplier {
-            actor.speed = ((actor.speed as f32) * speed_mult) as u32;
+            actor.speed = scale_percent(actor.speed as i32, multiplier_to_percent(speed_mult)) as u32;
         }
         
         if let Some(stamina_mult) = variation.stamina_multiplier {
-            actor.max_stamina = ((actor.max_stamina as f32) * stamina_mult) as u32;
+            actor.max_stamina = scale_percent(actor.max_stamina as i32, multiplier_to_percent(stamina_mult)) as u32;
             actor.stamina = actor.max_stamina;
         }
         
@@ -280,19 +354,49 @@ impl Battle {
         let mut events = Vec::new();
         self.tick_count += 1;
         
-        // 1. Apply bleeding damage to all actors
+        // 0. Bring in reinforcements that are due this tick
+        let arrival_events = self.spawn_reinforcements();
+        events.extend(arrival_events);
+        
+        // 1. Drain blood from open wounds and let venom work; flyers that
+        // faint fall
+        let mut fainted_flyers: Vec<u32> = Vec::new();
         for actor in self.team_a.iter_mut().chain(self.team_b.iter_mut()) {
             if actor.is_alive() {
                 let bleed_events = CombatResolver::apply_bleeding(actor);
+                if bleed_events.iter().any(|e| matches!(e, BattleEvent::Grounded { .. })) && actor.is_alive() {
+                    fainted_flyers.push(actor.id);
+                }
                 events.extend(bleed_events);
             }
+            if actor.is_alive() {
+                events.extend(VenomResolver::apply_toxin(actor));
+            }
+        }
+        
+        for actor_id in fainted_flyers {
+            let landing_events = self.resolve_landing(actor_id);
+            events.extend(landing_events);
         }
         
-        // 2. Regenerate stamina
+        // 2. Regenerate stamina, let pain fade and nausea build
+        let mut exhausted_flyers: Vec<u32> = Vec::new();
         for actor in self.team_a.iter_mut().chain(self.team_b.iter_mut()) {
             if actor.is_alive() {
-                let regen = (actor.max_stamina / 10).max(5);
-                actor.stamina = (actor.stamina + regen).min(actor.max_stamina);
+                let pain_events = PainResolver::recover(actor);
+                events.extend(pain_events);
+                events.extend(NauseaResolver::churn(actor));
+                
+                // Staying airborne costs stamina; exhausted flyers glide down
+                if !actor.recover_stamina() {
+                    actor.airborne = false;
+                    events.push(BattleEvent::StatusChange {
+                        actor_id: actor.id,
+                        status: "airborne".to_string(),
+                        active: false,
+                    });
+                    exhausted_flyers.push(actor.id);
+                }
                 
                 // Slowly restore morale over time (1 point per tick if not fleeing)
                 if !actor.is_fleeing() {
@@ -301,264 +405,981 @@ impl Battle {
             }
         }
         
-        // 3. Build turn order based on speed (highest speed acts first)
-        // Collect all alive actors with their indices and team
-        let mut turn_order: Vec<(u32, u8, u32)> = Vec::new(); // (actor_id, team, speed)
+        for actor_id in exhausted_flyers {
+            let landing_events = self.resolve_landing(actor_id);
+            events.extend(landing_events);
+        }
         
-        for actor in &self.team_a {
-            if actor.is_alive() {
-                turn_order.push((actor.id, 0, actor.speed));
+        // 3. Radiate auras over everyone in reach
+        let aura_events = self.apply_auras();
+        events.extend(aura_events);
+        
+        // 4. Accumulate action energy from speed. Stunned and shocked actors
+        // lose the tick entirely.
+        for actor in self.team_a.iter_mut().chain(self.team_b.iter_mut()) {
+            if actor.is_alive() && !actor.is_incapacitated() {
+                actor.gain_energy();
             }
         }
         
-        for actor in &self.team_b {
-            if actor.is_alive() {
-                turn_order.push((actor.id, 1, actor.speed));
+        // 5. Spend energy in initiative rounds. Each round every actor with
+        // enough energy takes one action, so faster actors act more often
+        // per tick and slowed (crippled) actors fall behind.
+        let mut finished_turn: Vec<u32> = Vec::new();
+        loop {
+            let turn_order = self.build_turn_order(&finished_turn);
+            if turn_order.is_empty() {
+                break;
+            }
+            
+            for (actor_id, team) in turn_order {
+                // Find the current actor
+                let actor_ref = if team == 0 {
+                    self.team_a.iter().find(|a| a.id == actor_id)
+                } else {
+                    self.team_b.iter().find(|a| a.id == actor_id)
+                };
+                
+                let actor = match actor_ref {
+                    Some(actor) if actor.is_alive() && !actor.is_incapacitated() && actor.can_afford(Action::MOVE_COST) => actor,
+                    _ => {
+                        finished_turn.push(actor_id);
+                        continue;
+                    }
+                };
+                
+                // Select action for this actor based on team
+                let action = if team == 0 {
+                    SimpleAI::select_action(&mut self.rng, actor, &self.team_a, &self.team_b, &self.attack_table)
+                } else {
+                    SimpleAI::select_action(&mut self.rng, actor, &self.team_b, &self.team_a, &self.attack_table)
+                };
+                
+                let action = match action {
+                    Some(action) => action,
+                    None => {
+                        finished_turn.push(actor_id);
+                        continue;
+                    }
+                };
+                
+                // Actions the actor can't afford (or that cost nothing) end its turn
+                let cost = action.energy_cost();
+                if cost == 0 || !actor.can_afford(cost) {
+                    finished_turn.push(actor_id);
+                    continue;
+                }
+                
+                if let Some(actor) = self.find_actor_mut(actor_id) {
+                    actor.spend_energy(cost);
+                    
+                    // Doing anything else drops the guard
+                    if actor.defending && !matches!(action, Action::Defend { .. }) {
+                        actor.defending = false;
+                        events.push(BattleEvent::StatusChange {
+                            actor_id,
+                            status: "defending".to_string(),
+                            active: false,
+                        });
+                    }
+                }
+                
+                let action_events = self.execute_action(action);
+                let debris_events = self.throw_debris(&action_events);
+                events.extend(action_events);
+                events.extend(debris_events);
+                
+                // Holds break when either side dies, drifts apart or loses its grip
+                let release_events = self.release_broken_grapples();
+                events.extend(release_events);
             }
         }
         
-        // Sort by speed (descending), then by actor_id for determinism
-        turn_order.sort_by(|a, b| {
-            b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0))
-        });
+        // 6. Leave corpses, gibs and blood on the ground
+        self.record_ground_layer(&mut events);
         
-        // 4. Process each actor's turn individually
-        for (actor_id, team, _speed) in turn_order {
-            // Find the current actor
-            let actor_ref = if team == 0 {
-                self.team_a.iter().find(|a| a.id == actor_id)
-            } else {
-                self.team_b.iter().find(|a| a.id == actor_id)
-            };
-            
-            if actor_ref.is_none() || !actor_ref.unwrap().is_alive() {
-                continue;
+        // 7. Check win conditions. A wiped-out team with reinforcements
+        // still on the way hasn't lost yet.
+        let team_a_alive = self.team_a.iter().filter(|a| a.is_alive()).count();
+        let team_b_alive = self.team_b.iter().filter(|a| a.is_alive()).count();
+        let team_a_pending = self.reinforcements.iter().any(|r| r.actor.team == 0);
+        let team_b_pending = self.reinforcements.iter().any(|r| r.actor.team == 1);
+        
+        if team_a_alive == 0 && !team_a_pending {
+            self.finished = true;
+            self.winner = Some(1);
+        } else if team_b_alive == 0 && !team_b_pending {
+            self.finished = true;
+            self.winner = Some(0);
+        }
+        
+        events
+    }
+    
+    /// Place every reinforcement whose arrival tick has come. Arrivals sharing
+    /// a team, edge and formation enter together; if their edge is too crowded
+    /// to fit them they wait and try again next tick.
+    fn spawn_reinforcements(&mut self) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        let tick = self.tick_count;
+        let (due, pending): (Vec<Reinforcement>, Vec<Reinforcement>) = std::mem::take(&mut self.reinforcements)
+            .into_iter()
+            .partition(|r| r.arrive_tick <= tick);
+        self.reinforcements = pending;
+        if due.is_empty() {
+            return events;
+        }
+        
+        let mut planner = SpawnPlanner::new(&self.grid, self.arena.spawn_depth);
+        for actor in self.team_a.iter().chain(self.team_b.iter()) {
+            if actor.is_alive() && !actor.airborne {
+                planner.block(actor.x, actor.y);
+            }
+        }
+        
+        // Explicit positions first, falling back to the group's formation if taken
+        let mut placed: Vec<(Reinforcement, (i32, i32))> = Vec::new();
+        let mut groups: Vec<((u8, SpawnSide, Formation), Vec<Reinforcement>)> = Vec::new();
+        for arrival in due {
+            if let Some((x, y)) = arrival.position {
+                if planner.reserve(x, y).is_ok() {
+                    placed.push((arrival, (x, y)));
+                    continue;
+                }
+            }
+            let key = (arrival.actor.team, arrival.edge, arrival.formation);
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, members)) => members.push(arrival),
+                None => groups.push((key, vec![arrival])),
             }
+        }
+        
+        let mut waiting = Vec::new();
+        for ((_, edge, formation), members) in groups {
+            match planner.plan(&mut self.rng, formation, edge, members.len()) {
+                Ok(cells) => placed.extend(members.into_iter().zip(cells)),
+                Err(_) => waiting.extend(members),
+            }
+        }
+        
+        // Crowded-out arrivals go back to the front of the queue
+        waiting.append(&mut self.reinforcements);
+        self.reinforcements = waiting;
+        
+        for (arrival, (x, y)) in placed {
+            let mut actor = arrival.actor;
+            actor.x = x;
+            actor.y = y;
             
-            // Select action for this actor based on team
-            let action = if team == 0 {
-                SimpleAI::select_action(
-                    &mut self.rng,
-                    actor_ref.unwrap(),
-                    &self.team_a,
-                    &self.team_b,
-                )
-            } else {
-                SimpleAI::select_action(
-                    &mut self.rng,
-                    actor_ref.unwrap(),
-                    &self.team_b,
-                    &self.team_a,
-                )
-            };
+            events.push(BattleEvent::Spawn {
+                actor_id: actor.id,
+                team: actor.team,
+                species_id: actor.species_id.clone(),
+                x,
+                y,
+            });
             
-            if action.is_none() {
-                continue;
+            if actor.team == 0 {
+                self.team_a.push(actor);
+            } else {
+                self.team_b.push(actor);
             }
-            
-            // Execute the action
-            match action.unwrap() {
-                Action::Attack {
-                    attacker_id,
-                    target_id,
-                    attack_id,
-                } => {
-                    // Determine which teams the attacker and defender are on
-                    let attacker_in_a = self.team_a.iter().any(|a| a.id == attacker_id);
-                    let defender_in_a = self.team_a.iter().any(|a| a.id == target_id);
-                    
-                    // Can't attack same team
-                    if attacker_in_a == defender_in_a {
-                        continue;
-                    }
+        }
+        
+        events
+    }
+    
+    /// Execute a single action chosen by an actor, returning the resulting events
+    fn execute_action(&mut self, action: Action) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        match action {
+            Action::Attack {
+                attacker_id,
+                target_id,
+                attack_id,
+            } => {
+                // Determine which teams the attacker and defender are on
+                let attacker_in_a = self.team_a.iter().any(|a| a.id == attacker_id);
+                let defender_in_a = self.team_a.iter().any(|a| a.id == target_id);
+                
+                // Can't attack same team
+                if attacker_in_a == defender_in_a {
+                    return events;
+                }
+                
+                // Pinned actors can only struggle
+                if self.find_actor(attacker_id).is_some_and(|a| a.pinned) {
+                    return events;
+                }
+                
+                // Get attack data from attacker
+                let attack_opt = {
+                    let attacker = if attacker_in_a {
+                        self.team_a.iter().find(|a| a.id == attacker_id)
+                    } else {
+                        self.team_b.iter().find(|a| a.id == attacker_id)
+                    };
                     
-                    // Get attack data from attacker
-                    let attack_opt = {
+                    attacker
+                        .and_then(|a| {
+                            a.get_available_attacks(&self.attack_table)
+                                .into_iter()
+                                .find(|atk| atk.attack_id == attack_id && !atk.is_hold())
+                        })
+                };
+                
+                if let Some(attack) = attack_opt {
+                    // Check range before attacking - the target must be within the attack's reach
+                    let in_range = {
                         let attacker = if attacker_in_a {
                             self.team_a.iter().find(|a| a.id == attacker_id)
                         } else {
                             self.team_b.iter().find(|a| a.id == attacker_id)
                         };
-                        
-                        attacker
-                            .and_then(|a| {
-                                a.get_available_attacks()
-                                    .into_iter()
-                                    .find(|atk| atk.attack_id == attack_id)
-                            })
+                        let defender = if defender_in_a {
+                            self.team_a.iter().find(|a| a.id == target_id)
+                        } else {
+                            self.team_b.iter().find(|a| a.id == target_id)
+                        };
+                        if let (Some(atk), Some(def)) = (attacker, defender) {
+                            CombatResolver::is_in_range(atk, def, attack.range_sq())
+                        } else {
+                            false
+                        }
                     };
                     
-                    if let Some(attack) = attack_opt {
-                        // Check range before attacking - must be adjacent (distance <= 1.5 for diagonal)
-                        let in_range = {
-                            let attacker = if attacker_in_a {
-                                self.team_a.iter().find(|a| a.id == attacker_id)
-                            } else {
-                                self.team_b.iter().find(|a| a.id == attacker_id)
-                            };
-                            let defender = if defender_in_a {
-                                self.team_a.iter().find(|a| a.id == target_id)
-                            } else {
-                                self.team_b.iter().find(|a| a.id == target_id)
-                            };
-                            if let (Some(atk), Some(def)) = (attacker, defender) {
-                                CombatResolver::is_in_range(atk, def, 1.5)
-                            } else {
-                                false
-                            }
-                        };
+                    if !in_range {
+                        return events; // Skip attack if out of reach
+                    }
+                    
+                    let flankers = self.count_flankers(attacker_id, target_id, attacker_in_a);
+                    
+                    // Resolve combat based on team configuration
+                    let combat_events = if attacker_in_a {
+                        // Team A attacks Team B
+                        let attacker = self.team_a.iter_mut().find(|a| a.id == attacker_id);
+                        let defender = self.team_b.iter_mut().find(|a| a.id == target_id);
                         
-                        if !in_range {
-                            continue; // Skip attack if not in melee range
+                        if let (Some(attacker), Some(defender)) = (attacker, defender) {
+                            CombatResolver::resolve_attack(&mut self.rng, attacker, defender, &attack, flankers)
+                        } else {
+                            Vec::new()
                         }
+                    } else {
+                        // Team B attacks Team A
+                        let attacker = self.team_b.iter_mut().find(|a| a.id == attacker_id);
+                        let defender = self.team_a.iter_mut().find(|a| a.id == target_id);
                         
-                        // Resolve combat based on team configuration
-                        let combat_events = if attacker_in_a {
-                            // Team A attacks Team B
-                            let attacker = self.team_a.iter_mut().find(|a| a.id == attacker_id);
-                            let defender = self.team_b.iter_mut().find(|a| a.id == target_id);
-                            
-                            if let (Some(attacker), Some(defender)) = (attacker, defender) {
-                                CombatResolver::resolve_attack(&mut self.rng, attacker, defender, &attack)
-                            } else {
-                                Vec::new()
-                            }
+                        if let (Some(attacker), Some(defender)) = (attacker, defender) {
+                            CombatResolver::resolve_attack(&mut self.rng, attacker, defender, &attack, flankers)
                         } else {
-                            // Team B attacks Team A
-                            let attacker = self.team_b.iter_mut().find(|a| a.id == attacker_id);
-                            let defender = self.team_a.iter_mut().find(|a| a.id == target_id);
-                            
-                            if let (Some(attacker), Some(defender)) = (attacker, defender) {
-                                CombatResolver::resolve_attack(&mut self.rng, attacker, defender, &attack)
-                            } else {
-                                Vec::new()
-                            }
-                        };
-                        
-                        // Check for ally deaths BEFORE extending events
-                        let defender_died = combat_events.iter().any(|e| matches!(e, BattleEvent::Death { .. }));
-                        events.extend(combat_events);
-                        
-                        // Apply morale penalties if ally died
-                        if defender_died {
-                            let defender_team = if defender_in_a { 0 } else { 1 };
-                            // Apply morale penalty to all allies (not distance-based for simplicity)
-                            let allies = if defender_team == 0 { &mut self.team_a } else { &mut self.team_b };
-                            for ally in allies.iter_mut() {
-                                if ally.is_alive() && ally.id != target_id {
-                                    ally.reduce_morale(5); // Ally death morale penalty
-                                }
-                            }
+                            Vec::new()
                         }
+                    };
+                    
+                    // Check for ally deaths BEFORE extending events
+                    let mut defender_died = combat_events.iter().any(|e| matches!(e, BattleEvent::Death { .. }));
+                    let landed = combat_events.iter().any(|e| matches!(e, BattleEvent::Hit { .. }));
+                    let grounded: Vec<u32> = combat_events
+                        .iter()
+                        .filter_map(|e| match e {
+                            BattleEvent::Grounded { actor_id, .. } => Some(*actor_id),
+                            _ => None,
+                        })
+                        .collect();
+                    events.extend(combat_events);
+                    
+                    // Flyers knocked out of the sky need somewhere to land
+                    for grounded_id in grounded {
+                        let landing_events = self.resolve_landing(grounded_id);
+                        events.extend(landing_events);
                     }
+                    
+                    // Heavy blunt hits throw light defenders backwards
+                    if landed && !defender_died {
+                        let attacker_mass = self.find_actor(attacker_id).map_or(0, |a| a.mass_g);
+                        let defender_mass = self.find_actor(target_id).map_or(0, |a| a.mass_g);
+                        let distance = CombatResolver::knockback_distance(&attack, attacker_mass, defender_mass);
+                        if distance > 0 {
+                            let knockback_events = self.apply_knockback(attacker_id, target_id, distance);
+                            defender_died = knockback_events.iter().any(|e| matches!(e, BattleEvent::Death { .. }));
+                            events.extend(knockback_events);
+                        }
+                    }
+                    
+                    // Apply morale penalties if ally died
+                    if defender_died {
+                        self.apply_ally_death_morale(target_id);
+                    }
+                }
+            }
+            Action::Move {
+                actor_id,
+                target_x,
+                target_y,
+            } => {
+                // Held actors are stuck until they break free
+                if self.find_actor(actor_id).is_some_and(|a| a.grappled_by.is_some()) {
+                    return events;
                 }
-                Action::Move {
-                    actor_id,
-                    target_x,
-                    target_y,
-                } => {
-                    // Check if any other actor is at the target position
-                    let occupant_id = self.find_actor_at(target_x, target_y, Some(actor_id));
+                
+                // Check if any other actor in the same layer is at the target position
+                let mover_airborne = self.find_actor(actor_id).is_some_and(|a| a.airborne);
+                let occupant_id = self.find_actor_at(target_x, target_y, Some(actor_id), mover_airborne);
+                let mover_ethereal = self.find_actor(actor_id).is_some_and(|a| a.is_ethereal());
+                
+                if occupant_id.is_some() && mover_ethereal {
+                    // Ethereal movers drift straight through whoever is in the way
+                    let phase_events = self.phase_through(actor_id, target_x, target_y);
+                    events.extend(phase_events);
+                } else if let Some(occ_id) = occupant_id {
+                    let (mover_team, mover_mass) = self.find_actor(actor_id).map_or((0, 0), |a| (a.team, a.mass_g));
+                    let (occupant_team, occupant_mass) = self.find_actor(occ_id).map_or((0, 0), |a| (a.team, a.mass_g));
                     
-                    if let Some(occ_id) = occupant_id {
+                    if mover_team != occupant_team && CombatResolver::can_trample(mover_mass, occupant_mass) {
+                        // Tiny enemies get run over
+                        let trample_events = self.trample(actor_id, occ_id, target_x, target_y);
+                        events.extend(trample_events);
+                    } else {
                         // Collision! Try to bump the occupant
                         let bump_events = self.try_bump_actor(actor_id, occ_id, target_x, target_y);
                         events.extend(bump_events);
-                    } else {
-                        // No collision, normal move
-                        if let Some(actor) = self
-                            .team_a
-                            .iter_mut()
-                            .find(|a| a.id == actor_id)
-                            .or_else(|| self.team_b.iter_mut().find(|a| a.id == actor_id))
-                        {
-                            if actor.is_alive() && actor.speed > 0 {
-                                // Check if target is walkable
-                                if self.grid.is_walkable(target_x, target_y) {
-                                    let old_x = actor.x;
-                                    let old_y = actor.y;
-                                    actor.x = target_x;
-                                    actor.y = target_y;
-                                    
-                                    events.push(BattleEvent::Move {
+                    }
+                } else {
+                    // No collision, normal move
+                    if let Some(actor) = self
+                        .team_a
+                        .iter_mut()
+                        .find(|a| a.id == actor_id)
+                        .or_else(|| self.team_b.iter_mut().find(|a| a.id == actor_id))
+                    {
+                        if actor.is_alive() && actor.speed > 0 {
+                            // Check if target is walkable (or flyable for airborne actors)
+                            let passable = if actor.airborne {
+                                self.grid.is_flyable(target_x, target_y)
+                            } else {
+                                self.grid.is_walkable(target_x, target_y)
+                            };
+                            if passable {
+                                let old_x = actor.x;
+                                let old_y = actor.y;
+                                actor.x = target_x;
+                                actor.y = target_y;
+                                
+                                events.push(BattleEvent::Move {
+                                    actor_id,
+                                    from_x: old_x,
+                                    from_y: old_y,
+                                    to_x: target_x,
+                                    to_y: target_y,
+                                });
+                                
+                                // Walkers can slip in a puddle of vomit and lose their footing
+                                let vomit = self.grid.decal_amount(DecalKind::Vomit, target_x, target_y);
+                                if !actor.airborne && vomit > 0
+                                    && self.rng.gen_range(0..100) < NauseaResolver::slip_chance(vomit)
+                                {
+                                    actor.energy = 0;
+                                    events.push(BattleEvent::StatusChange {
                                         actor_id,
-                                        from_x: old_x,
-                                        from_y: old_y,
-                                        to_x: target_x,
-                                        to_y: target_y,
+                                        status: "slipped".to_string(),
+                                        active: true,
                                     });
                                 }
                             }
                         }
                     }
                 }
-                Action::Defend { .. } | Action::Wait { .. } => {
-                    // No-op for now
+            }
+            Action::TakeOff { actor_id } => {
+                if let Some(actor) = self.find_actor_mut(actor_id) {
+                    if actor.is_alive() && !actor.airborne && actor.can_fly() && actor.grappled_by.is_none() {
+                        actor.airborne = true;
+                        events.push(BattleEvent::StatusChange {
+                            actor_id,
+                            status: "airborne".to_string(),
+                            active: true,
+                        });
+                    }
                 }
             }
+            Action::Land { actor_id } => {
+                let (x, y) = match self.find_actor(actor_id) {
+                    Some(actor) if actor.is_alive() && actor.airborne => (actor.x, actor.y),
+                    _ => return events,
+                };
+                
+                // Only touch down on open ground
+                if self.grid.is_walkable(x, y) && self.find_actor_at(x, y, Some(actor_id), false).is_none() {
+                    if let Some(actor) = self.find_actor_mut(actor_id) {
+                        actor.airborne = false;
+                    }
+                    events.push(BattleEvent::StatusChange {
+                        actor_id,
+                        status: "airborne".to_string(),
+                        active: false,
+                    });
+                }
+            }
+            Action::Grapple {
+                attacker_id,
+                target_id,
+            } => {
+                let in_range = match (self.find_actor(attacker_id), self.find_actor(target_id)) {
+                    (Some(atk), Some(def)) => {
+                        atk.team != def.team
+                            && atk.is_alive()
+                            && def.is_alive()
+                            && !atk.pinned
+                            && CombatResolver::is_in_range(atk, def, MELEE_RANGE_SQ)
+                    }
+                    _ => false,
+                };
+                
+                if in_range {
+                    if let Some((grappler, target)) = find_pair_mut(&mut self.team_a, &mut self.team_b, attacker_id, target_id) {
+                        events.extend(GrappleResolver::seize(&mut self.rng, &self.attack_table, grappler, target));
+                    }
+                }
+            }
+            Action::Pin { actor_id } => {
+                let held_id = match self.find_actor(actor_id).and_then(|a| a.grappling) {
+                    Some(id) => id,
+                    None => return events,
+                };
+                if let Some((grappler, held)) = find_pair_mut(&mut self.team_a, &mut self.team_b, actor_id, held_id) {
+                    events.extend(GrappleResolver::pin(&mut self.rng, grappler, held));
+                }
+            }
+            Action::Tear { actor_id } => {
+                let held_id = match self.find_actor(actor_id).and_then(|a| a.grappling) {
+                    Some(id) => id,
+                    None => return events,
+                };
+                let tear_events = match find_pair_mut(&mut self.team_a, &mut self.team_b, actor_id, held_id) {
+                    Some((grappler, held)) => GrappleResolver::tear(&mut self.rng, &self.attack_table, grappler, held),
+                    None => return events,
+                };
+                
+                let held_died = tear_events.iter().any(|e| matches!(e, BattleEvent::Death { .. }));
+                let grounded = tear_events.iter().any(|e| matches!(e, BattleEvent::Grounded { .. }));
+                events.extend(tear_events);
+                
+                if grounded {
+                    events.extend(self.resolve_landing(held_id));
+                }
+                if held_died {
+                    self.apply_ally_death_morale(held_id);
+                }
+            }
+            Action::Throw { actor_id } => {
+                let held_id = match self.find_actor(actor_id).and_then(|a| a.grappling) {
+                    Some(id) => id,
+                    None => return events,
+                };
+                let distance = match find_pair_mut(&mut self.team_a, &mut self.team_b, actor_id, held_id) {
+                    Some((thrower, held)) if thrower.stamina >= GRAPPLE_STAMINA_COST => {
+                        thrower.stamina -= GRAPPLE_STAMINA_COST;
+                        GrappleResolver::release(thrower, held);
+                        GrappleResolver::throw_distance(thrower.mass_g, held.mass_g)
+                    }
+                    _ => return events,
+                };
+                events.push(BattleEvent::StatusChange {
+                    actor_id: held_id,
+                    status: "grappled".to_string(),
+                    active: false,
+                });
+                
+                if let Some((from_x, from_y, to_x, to_y, impact_damage)) = self.fling_actor(actor_id, held_id, distance) {
+                    events.push(BattleEvent::Throw {
+                        thrower_id: actor_id,
+                        target_id: held_id,
+                        from_x,
+                        from_y,
+                        to_x,
+                        to_y,
+                        impact_damage: impact_damage as u32,
+                    });
+                    
+                    if self.find_actor(held_id).is_some_and(|a| !a.is_alive()) {
+                        events.push(BattleEvent::Death {
+                            actor_id: held_id,
+                            x: to_x,
+                            y: to_y,
+                        });
+                        self.apply_ally_death_morale(held_id);
+                    } else {
+                        events.extend(self.inflict_pain(held_id, impact_damage));
+                    }
+                }
+            }
+            Action::Struggle { actor_id } => {
+                let grappler_id = match self.find_actor(actor_id).and_then(|a| a.grappled_by) {
+                    Some(id) => id,
+                    None => return events,
+                };
+                if let Some((held, grappler)) = find_pair_mut(&mut self.team_a, &mut self.team_b, actor_id, grappler_id) {
+                    events.extend(GrappleResolver::struggle(&mut self.rng, held, grappler));
+                }
+            }
+            Action::Defend { actor_id } => {
+                if let Some(actor) = self.find_actor_mut(actor_id) {
+                    if actor.is_alive() && !actor.defending {
+                        actor.defending = true;
+                        events.push(BattleEvent::StatusChange {
+                            actor_id,
+                            status: "defending".to_string(),
+                            active: true,
+                        });
+                    }
+                }
+            }
+            Action::Wait { .. } => {
+                // No-op for now
+            }
         }
         
-        // 5. Check win conditions
-        let team_a_alive = self.team_a.iter().filter(|a| a.is_alive()).count();
-        let team_b_alive = self.team_b.iter().filter(|a| a.is_alive()).count();
+        events
+    }
+    
+    /// Apply the morale penalty for a fallen ally to everyone left on its team
+    fn apply_ally_death_morale(&mut self, dead_id: u32) {
+        let dead_in_a = self.team_a.iter().any(|a| a.id == dead_id);
+        // Apply morale penalty to all allies (not distance-based for simplicity)
+        let allies = if dead_in_a { &mut self.team_a } else { &mut self.team_b };
+        for ally in allies.iter_mut() {
+            if ally.is_alive() && ally.id != dead_id {
+                ally.reduce_morale(5); // Ally death morale penalty
+            }
+        }
+    }
+    
+    /// Release every grapple that can no longer be held: either side is dead,
+    /// they are no longer adjacent or in the same layer, or the grappler has
+    /// lost all of its grasping parts or been stunned senseless
+    fn release_broken_grapples(&mut self) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
         
-        if team_a_alive == 0 {
-            self.finished = true;
-            self.winner = Some(1);
-        } else if team_b_alive == 0 {
-            self.finished = true;
-            self.winner = Some(0);
+        let holds: Vec<(u32, u32)> = self
+            .team_a
+            .iter()
+            .chain(self.team_b.iter())
+            .filter_map(|a| a.grappling.map(|held_id| (a.id, held_id)))
+            .collect();
+        
+        for (grappler_id, held_id) in holds {
+            let broken = match (self.find_actor(grappler_id), self.find_actor(held_id)) {
+                (Some(grappler), Some(held)) => {
+                    !grappler.is_alive()
+                        || !held.is_alive()
+                        || grappler.airborne != held.airborne
+                        || !grappler.can_grapple(&self.attack_table)
+                        || grappler.is_incapacitated()
+                        || !CombatResolver::is_in_range(grappler, held, MELEE_RANGE_SQ)
+                }
+                _ => true,
+            };
+            if !broken {
+                continue;
+            }
+            
+            match find_pair_mut(&mut self.team_a, &mut self.team_b, grappler_id, held_id) {
+                Some((grappler, held)) => {
+                    GrappleResolver::release(grappler, held);
+                    if held.is_alive() {
+                        events.push(BattleEvent::StatusChange {
+                            actor_id: held_id,
+                            status: "grappled".to_string(),
+                            active: false,
+                        });
+                    }
+                }
+                None => {
+                    if let Some(grappler) = self.find_actor_mut(grappler_id) {
+                        grappler.grappling = None;
+                    }
+                }
+            }
         }
         
         events
     }
     
-    /// Find an actor at a position (excluding a specific actor)
-    fn find_actor_at(&self, x: i32, y: i32, exclude: Option<u32>) -> Option<u32> {
-        for actor in &self.team_a {
-            if actor.is_alive() && actor.x == x && actor.y == y {
-                if exclude.map_or(true, |ex| actor.id != ex) {
-                    return Some(actor.id);
+    /// Build the initiative order for one round of actions: alive actors that
+    /// can still afford an action, most banked energy first, then by speed and id
+    fn build_turn_order(&self, finished_turn: &[u32]) -> Vec<(u32, u8)> {
+        let mut turn_order: Vec<(u32, u8, u32, u32)> = Vec::new(); // (actor_id, team, energy, speed)
+        
+        for actor in self.team_a.iter().chain(self.team_b.iter()) {
+            if actor.is_alive()
+                && !actor.is_incapacitated()
+                && actor.can_afford(Action::MOVE_COST)
+                && !finished_turn.contains(&actor.id)
+            {
+                turn_order.push((actor.id, actor.team, actor.energy, actor.speed));
+            }
+        }
+        
+        // Sort by energy, then speed (descending), then by actor_id for determinism
+        turn_order.sort_by(|a, b| {
+            b.2.cmp(&a.2)
+                .then_with(|| b.3.cmp(&a.3))
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        
+        turn_order.into_iter().map(|(id, team, _, _)| (id, team)).collect()
+    }
+    
+    /// Record the physical aftermath of this tick's events on the grid's ground
+    /// layer, so the renderer draws gore from simulation state. Gibs that were
+    /// flung past the arena edge are pulled back inside.
+    fn record_ground_layer(&mut self, events: &mut [BattleEvent]) {
+        for event in events.iter_mut() {
+            match event {
+                BattleEvent::Sever { actor_id, part_id, gib_char, x, y } => {
+                    let (gx, gy) = self.grid.clamp_position(*x, *y);
+                    *x = gx;
+                    *y = gy;
+                    self.grid.add_gib(*actor_id, part_id.clone(), *gib_char, gx, gy);
+                    self.grid.add_decal(DecalKind::Blood, gx, gy, 1);
+                }
+                BattleEvent::Death { actor_id, x, y } => {
+                    let (actor_id, x, y) = (*actor_id, *x, *y);
+                    match self.find_actor(actor_id) {
+                        // Ethereal actors leave nothing behind
+                        Some(actor) if actor.is_ethereal() => {
+                            *event = BattleEvent::Dissipate { actor_id, x, y };
+                        }
+                        Some(actor) => {
+                            let (species_id, glyph) = (actor.species_id.clone(), actor.glyph);
+                            self.grid.add_corpse(actor_id, species_id, glyph, x, y);
+                        }
+                        None => {}
+                    }
+                }
+                BattleEvent::Bleed { actor_id, amount } => {
+                    // Bleeding actors leave a trail wherever they are this tick
+                    let position = self.find_actor(*actor_id).map(|a| (a.x, a.y));
+                    if let Some((bx, by)) = position {
+                        self.grid.add_decal(DecalKind::Blood, bx, by, *amount);
+                    }
+                }
+                BattleEvent::Shatter { actor_id, part_id, gib_char, x, y, .. } => {
+                    // Rubble from the shattered part
+                    self.grid.add_gib(*actor_id, part_id.clone(), *gib_char, *x, *y);
                 }
+                BattleEvent::Vomit { amount, x, y, .. } => {
+                    self.grid.add_decal(DecalKind::Vomit, *x, *y, *amount);
+                }
+                _ => {}
             }
         }
-        for actor in &self.team_b {
-            if actor.is_alive() && actor.x == x && actor.y == y {
-                if exclude.map_or(true, |ex| actor.id != ex) {
-                    return Some(actor.id);
+    }
+    
+    /// Move an actor that just came down from the air onto the nearest open
+    /// walkable cell if it landed on an obstacle or on top of another actor
+    fn resolve_landing(&mut self, actor_id: u32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        let (x, y) = match self.find_actor(actor_id) {
+            Some(actor) if actor.is_alive() => (actor.x, actor.y),
+            _ => return events,
+        };
+        
+        let is_open = |battle: &Self, cx: i32, cy: i32| {
+            battle.grid.is_walkable(cx, cy) && battle.find_actor_at(cx, cy, Some(actor_id), false).is_none()
+        };
+        
+        if is_open(self, x, y) {
+            return events;
+        }
+        
+        // Search outward in rings for the closest free cell
+        let max_radius = self.grid.width().max(self.grid.height());
+        for radius in 1..=max_radius {
+            for dy in -radius..=radius {
+                for dx in -radius..=radius {
+                    if dx.abs() != radius && dy.abs() != radius {
+                        continue;
+                    }
+                    let (nx, ny) = (x + dx, y + dy);
+                    if is_open(self, nx, ny) {
+                        if let Some(actor) = self.find_actor_mut(actor_id) {
+                            actor.x = nx;
+                            actor.y = ny;
+                        }
+                        events.push(BattleEvent::Move {
+                            actor_id,
+                            from_x: x,
+                            from_y: y,
+                            to_x: nx,
+                            to_y: ny,
+                        });
+                        return events;
+                    }
                 }
             }
         }
-        None
+        
+        events
+    }
+    
+    /// Count the attacker's living allies also in melee with the target on
+    /// its own layer
+    fn count_flankers(&self, attacker_id: u32, target_id: u32, attacker_in_a: bool) -> u32 {
+        let target = match self.find_actor(target_id) {
+            Some(target) => target,
+            None => return 0,
+        };
+        let allies = if attacker_in_a { &self.team_a } else { &self.team_b };
+        
+        allies
+            .iter()
+            .filter(|a| a.id != attacker_id && a.is_alive() && a.airborne == target.airborne)
+            .filter(|a| CombatResolver::is_in_range(a, target, MELEE_RANGE_SQ))
+            .count() as u32
+    }
+    
+    /// Find an actor by id on either team
+    fn find_actor(&self, actor_id: u32) -> Option<&Actor> {
+        self.team_a
+            .iter()
+            .find(|a| a.id == actor_id)
+            .or_else(|| self.team_b.iter().find(|a| a.id == actor_id))
+    }
+    
+    /// Find a mutable actor by id on either team
+    fn find_actor_mut(&mut self, actor_id: u32) -> Option<&mut Actor> {
+        self.team_a
+            .iter_mut()
+            .find(|a| a.id == actor_id)
+            .or_else(|| self.team_b.iter_mut().find(|a| a.id == actor_id))
+    }
+    
+    /// Find an actor at a position in the given layer (excluding a specific actor).
+    /// Airborne and grounded actors can share a cell.
+    fn find_actor_at(&self, x: i32, y: i32, exclude: Option<u32>, airborne: bool) -> Option<u32> {
+        self.team_a
+            .iter()
+            .chain(self.team_b.iter())
+            .find(|a| {
+                a.is_alive()
+                    && a.airborne == airborne
+                    && a.x == x
+                    && a.y == y
+                    && exclude.is_none_or(|ex| a.id != ex)
+            })
+            .map(|a| a.id)
+    }
+    
+    /// Apply every active aura to the actors in its reach, reporting who
+    /// entered or left each aura since the last tick
+    fn apply_auras(&mut self) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        let mut touches = Vec::new();
+        for source in self.team_a.iter().chain(self.team_b.iter()) {
+            for aura in source.auras.iter().filter(|aura| aura.is_active(source)) {
+                for target in self.team_a.iter().chain(self.team_b.iter()) {
+                    if aura.affects(source, target) {
+                        touches.push((source.id, target.id, aura.clone()));
+                    }
+                }
+            }
+        }
+        let contacts: Vec<(u32, u32, AuraKind)> = touches.iter().map(|(s, t, aura)| (*s, *t, aura.kind)).collect();
+        
+        // Living actors that slipped out of an aura
+        for &(source_id, actor_id, aura) in &self.aura_contacts {
+            if !contacts.contains(&(source_id, actor_id, aura)) && self.find_actor(actor_id).is_some_and(|a| a.is_alive()) {
+                events.push(BattleEvent::AuraLeave { actor_id, source_id, aura });
+            }
+        }
+        for &(source_id, actor_id, aura) in &contacts {
+            if !self.aura_contacts.contains(&(source_id, actor_id, aura)) {
+                events.push(BattleEvent::AuraEnter { actor_id, source_id, aura });
+            }
+        }
+        self.aura_contacts = contacts;
+        
+        let mut grounded = Vec::new();
+        for (_, target_id, aura) in touches {
+            if let Some(target) = self.find_actor_mut(target_id) {
+                let aura_events = AuraResolver::apply(&aura, target);
+                if aura_events.iter().any(|e| matches!(e, BattleEvent::Grounded { .. })) && target.is_alive() {
+                    grounded.push(target_id);
+                }
+                events.extend(aura_events);
+            }
+        }
+        
+        for actor_id in grounded {
+            let landing_events = self.resolve_landing(actor_id);
+            events.extend(landing_events);
+        }
+        
+        events
+    }
+    
+    /// Pelt every actor next to a shattered stone part with its debris.
+    /// Debris that shatters more stone throws debris of its own.
+    fn throw_debris(&mut self, events: &[BattleEvent]) -> Vec<BattleEvent> {
+        let mut debris_events = Vec::new();
+        let mut shatters: Vec<(u32, u32, i32, i32)> = events
+            .iter()
+            .filter_map(|e| match e {
+                BattleEvent::Shatter { actor_id, debris, x, y, .. } => Some((*actor_id, *debris, *x, *y)),
+                _ => None,
+            })
+            .collect();
+        
+        while let Some((source_id, debris, x, y)) = shatters.pop() {
+            let airborne = self.find_actor(source_id).is_some_and(|a| a.airborne);
+            let targets: Vec<u32> = self
+                .team_a
+                .iter()
+                .chain(self.team_b.iter())
+                .filter(|a| a.id != source_id && a.is_alive() && a.airborne == airborne)
+                .filter(|a| CombatResolver::distance_sq(x, y, a.x, a.y) <= MELEE_RANGE_SQ)
+                .map(|a| a.id)
+                .collect();
+            
+            for target_id in targets {
+                let rng = &mut self.rng;
+                let target = self
+                    .team_a
+                    .iter_mut()
+                    .chain(self.team_b.iter_mut())
+                    .find(|a| a.id == target_id);
+                let hit_events = match target {
+                    Some(target) => CombatResolver::debris_hit(rng, source_id, target, debris),
+                    None => continue,
+                };
+                
+                let mut grounded = Vec::new();
+                let mut died = Vec::new();
+                for event in &hit_events {
+                    match event {
+                        BattleEvent::Shatter { actor_id, debris, x, y, .. } => {
+                            shatters.push((*actor_id, *debris, *x, *y));
+                        }
+                        BattleEvent::Grounded { actor_id, .. } => grounded.push(*actor_id),
+                        BattleEvent::Death { actor_id, .. } => died.push(*actor_id),
+                        _ => {}
+                    }
+                }
+                debris_events.extend(hit_events);
+                
+                for actor_id in grounded {
+                    let landing_events = self.resolve_landing(actor_id);
+                    debris_events.extend(landing_events);
+                }
+                for actor_id in died {
+                    self.apply_ally_death_morale(actor_id);
+                }
+            }
+        }
+        
+        debris_events
+    }
+    
+    /// Carry an ethereal mover through an occupied cell to the cell beyond
+    /// it. Nothing happens if that cell is blocked too.
+    fn phase_through(&mut self, actor_id: u32, target_x: i32, target_y: i32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        let (from_x, from_y, airborne) = match self.find_actor(actor_id) {
+            Some(actor) => (actor.x, actor.y, actor.airborne),
+            None => return events,
+        };
+        let to_x = target_x + (target_x - from_x);
+        let to_y = target_y + (target_y - from_y);
+        
+        let passable = if airborne {
+            self.grid.is_flyable(to_x, to_y)
+        } else {
+            self.grid.is_walkable(to_x, to_y)
+        };
+        if !passable || self.find_actor_at(to_x, to_y, Some(actor_id), airborne).is_some() {
+            return events;
+        }
+        
+        if let Some(actor) = self.find_actor_mut(actor_id) {
+            actor.x = to_x;
+            actor.y = to_y;
+            events.push(BattleEvent::Move {
+                actor_id,
+                from_x,
+                from_y,
+                to_x,
+                to_y,
+            });
+        }
+        
+        events
     }
     
     /// Try to bump an actor out of a position, returning events
     fn try_bump_actor(&mut self, bumper_id: u32, bumped_id: u32, target_x: i32, target_y: i32) -> Vec<BattleEvent> {
-        let mut events = Vec::new();
+        let bumper_mass = self.find_actor(bumper_id).map_or(0, |a| a.mass_g);
+        let bumped_mass = self.find_actor(bumped_id).map_or(0, |a| a.mass_g);
         
-        // 50% chance to successfully bump
-        if self.rng.gen_range(0..100) >= 50 {
-            return events; // Failed to bump, no movement
+        // Heavier movers shove lighter occupants aside more reliably
+        let chance = CombatResolver::bump_chance(bumper_mass, bumped_mass);
+        if self.rng.gen_range(0..100) >= chance {
+            return Vec::new(); // Failed to bump, no movement
         }
         
+        self.shove_actor(bumper_id, bumped_id, target_x, target_y)
+    }
+    
+    /// Push an occupant out of a cell and move the shover into it. Heavier
+    /// shovers drive the occupant straight ahead; lighter ones can only
+    /// squeeze it aside in whatever direction happens to be free.
+    fn shove_actor(&mut self, bumper_id: u32, bumped_id: u32, target_x: i32, target_y: i32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        let (bumper_x, bumper_y, bumper_mass) = match self.find_actor(bumper_id) {
+            Some(a) => (a.x, a.y, a.mass_g),
+            None => return events,
+        };
+        let bumped_mass = self.find_actor(bumped_id).map_or(0, |a| a.mass_g);
+        
         // Find a free adjacent cell for the bumped actor
-        let directions = [
-            (1, 0), (-1, 0), (0, 1), (0, -1),
-            (1, 1), (1, -1), (-1, 1), (-1, -1),
-        ];
+        let push = ((target_x - bumper_x).signum(), (target_y - bumper_y).signum());
+        let candidates: Vec<(i32, i32)> = if bumper_mass > bumped_mass && push != (0, 0) {
+            push_directions(push)
+        } else {
+            let directions = [
+                (1, 0), (-1, 0), (0, 1), (0, -1),
+                (1, 1), (1, -1), (-1, 1), (-1, -1),
+            ];
+            
+            // Shuffle directions for randomness
+            let mut shuffled: Vec<(i32, i32)> = directions.to_vec();
+            for i in (1..shuffled.len()).rev() {
+                let j = self.rng.gen_range(0..=i);
+                shuffled.swap(i, j);
+            }
+            shuffled
+        };
         
-        // Shuffle directions for randomness
-        let mut shuffled: Vec<(i32, i32)> = directions.to_vec();
-        for i in (1..shuffled.len()).rev() {
-            let j = self.rng.gen_range(0..=i);
-            shuffled.swap(i, j);
-        }
+        // Bumped actors stay in their own layer
+        let airborne = self.find_actor(bumped_id).is_some_and(|a| a.airborne);
         
         let mut bump_target: Option<(i32, i32)> = None;
-        for (dx, dy) in shuffled {
+        for (dx, dy) in candidates {
             let new_x = target_x + dx;
             let new_y = target_y + dy;
-            if self.grid.is_walkable(new_x, new_y) && self.find_actor_at(new_x, new_y, None).is_none() {
+            let passable = if airborne {
+                self.grid.is_flyable(new_x, new_y)
+            } else {
+                self.grid.is_walkable(new_x, new_y)
+            };
+            if passable && self.find_actor_at(new_x, new_y, None, airborne).is_none() {
                 bump_target = Some((new_x, new_y));
                 break;
             }
@@ -602,6 +1423,154 @@ impl Battle {
         events
     }
     
+    /// Knock a defender back along the line of a heavy hit
+    fn apply_knockback(&mut self, attacker_id: u32, defender_id: u32, distance: i32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        let (from_x, from_y, x, y, impact_damage) = match self.fling_actor(attacker_id, defender_id, distance) {
+            Some(fling) => fling,
+            None => return events,
+        };
+        
+        events.push(BattleEvent::Knockback {
+            attacker_id,
+            defender_id,
+            from_x,
+            from_y,
+            to_x: x,
+            to_y: y,
+            impact_damage: impact_damage as u32,
+        });
+        
+        if self.find_actor(defender_id).is_some_and(|d| !d.is_alive()) {
+            events.push(BattleEvent::Death {
+                actor_id: defender_id,
+                x,
+                y,
+            });
+        } else {
+            events.extend(self.inflict_pain(defender_id, impact_damage));
+        }
+        
+        events
+    }
+    
+    /// Pain from damage dealt outside an attack (impacts, trampling). Flyers
+    /// that go into shock fall and need somewhere to land.
+    fn inflict_pain(&mut self, actor_id: u32, damage: i32) -> Vec<BattleEvent> {
+        let pain_events = match self.find_actor_mut(actor_id) {
+            Some(actor) => PainResolver::inflict(actor, damage),
+            None => return Vec::new(),
+        };
+        
+        let grounded = pain_events.iter().any(|e| matches!(e, BattleEvent::Grounded { .. }));
+        let mut events = pain_events;
+        if grounded {
+            events.extend(self.resolve_landing(actor_id));
+        }
+        
+        events
+    }
+    
+    /// Send an actor flying directly away from a source actor. If a wall, the
+    /// arena edge or another actor stops it short, it slams into the obstacle
+    /// and takes impact damage for the distance it couldn't travel.
+    /// Returns (from_x, from_y, to_x, to_y, impact_damage).
+    fn fling_actor(&mut self, source_id: u32, target_id: u32, distance: i32) -> Option<(i32, i32, i32, i32, i32)> {
+        let (source_x, source_y) = self.find_actor(source_id).map(|a| (a.x, a.y))?;
+        let (from_x, from_y, airborne, max_hp) = match self.find_actor(target_id) {
+            Some(t) if t.is_alive() => (t.x, t.y, t.airborne, t.max_hp),
+            _ => return None,
+        };
+        
+        let (dx, dy) = ((from_x - source_x).signum(), (from_y - source_y).signum());
+        if (dx, dy) == (0, 0) {
+            return None;
+        }
+        
+        let (mut x, mut y) = (from_x, from_y);
+        let mut travelled = 0;
+        while travelled < distance {
+            let (nx, ny) = (x + dx, y + dy);
+            let passable = if airborne {
+                self.grid.is_flyable(nx, ny)
+            } else {
+                self.grid.is_walkable(nx, ny)
+            };
+            if !passable || self.find_actor_at(nx, ny, Some(target_id), airborne).is_some() {
+                break;
+            }
+            x = nx;
+            y = ny;
+            travelled += 1;
+        }
+        
+        let impact_damage = (max_hp / 10).max(1) * (distance - travelled);
+        
+        let target = self.find_actor_mut(target_id)?;
+        target.x = x;
+        target.y = y;
+        if impact_damage > 0 {
+            target.take_damage(impact_damage);
+        }
+        
+        Some((from_x, from_y, x, y, impact_damage))
+    }
+    
+    /// A much heavier mover runs straight over a tiny enemy in its path,
+    /// crushing it and shoving it out of the way if it survives
+    fn trample(&mut self, trampler_id: u32, victim_id: u32, target_x: i32, target_y: i32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        let (victim_alive, damage) = match self.find_actor_mut(victim_id) {
+            Some(victim) => {
+                let damage = CombatResolver::trample_damage(victim);
+                victim.take_damage(damage as i32);
+                victim.reduce_morale(15);
+                
+                events.push(BattleEvent::Trample {
+                    trampler_id,
+                    victim_id,
+                    damage,
+                    x: target_x,
+                    y: target_y,
+                });
+                
+                if !victim.is_alive() {
+                    events.push(BattleEvent::Death {
+                        actor_id: victim_id,
+                        x: target_x,
+                        y: target_y,
+                    });
+                }
+                (victim.is_alive(), damage as i32)
+            }
+            None => return events,
+        };
+        
+        if victim_alive {
+            events.extend(self.inflict_pain(victim_id, damage));
+            events.extend(self.shove_actor(trampler_id, victim_id, target_x, target_y));
+            return events;
+        }
+        
+        self.apply_ally_death_morale(victim_id);
+        if let Some(trampler) = self.find_actor_mut(trampler_id) {
+            let (from_x, from_y) = (trampler.x, trampler.y);
+            trampler.x = target_x;
+            trampler.y = target_y;
+            events.push(BattleEvent::Move {
+                actor_id: trampler_id,
+                from_x,
+                from_y,
+                to_x: target_x,
+                to_y: target_y,
+            });
+        }
+        
+        events
+    }
+    
     pub fn is_finished(&self) -> bool {
         self.finished
     }
@@ -630,14 +1599,173 @@ struct BasicActorData {
     glyph: char,
 }
 
+/// A team as passed in from JSON: either a bare array of members, or an
+/// object with a team-wide formation and a `members` array
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TeamData {
+    Members(Vec<TeamMemberData>),
+    Composition(TeamComposition),
+}
+
 #[derive(Debug, Deserialize)]
+struct TeamComposition {
+    #[serde(default)]
+    formation: Option<Formation>,
+    members: Vec<TeamMemberData>,
+    /// Groups of reinforcements arriving after the battle starts
+    #[serde(default)]
+    waves: Vec<WaveData>,
+}
+
+/// A group of late arrivals, e.g. `{"arrive_tick": 50, "every": 50,
+/// "repeat": 3, "edge": "top", "members": [...]}`
+#[derive(Debug, Deserialize)]
+struct WaveData {
+    arrive_tick: u64,
+    #[serde(default)]
+    edge: Option<SpawnSide>,
+    #[serde(default)]
+    formation: Option<Formation>,
+    /// Ticks between repeats of this wave
+    #[serde(default)]
+    every: Option<u64>,
+    /// How many times the wave arrives
+    #[serde(default = "default_wave_repeat")]
+    repeat: u32,
+    members: Vec<TeamMemberData>,
+}
+
+fn default_wave_repeat() -> u32 {
+    1
+}
+
+/// A combatant waiting off the field for its arrival tick
+#[derive(Debug, Serialize)]
+struct Reinforcement {
+    arrive_tick: u64,
+    edge: SpawnSide,
+    formation: Formation,
+    #[serde(skip)]
+    position: Option<(i32, i32)>,
+    actor: Actor,
+}
+
+#[derive(Debug, Clone, Deserialize)]
 struct TeamMemberData {
     species_id: String,
     #[serde(default)]
     variation: Option<IndividualVariation>,
+    /// Overrides the team formation for this member's species group
+    #[serde(default)]
+    formation: Option<Formation>,
+    /// Explicit spawn cell, bypassing the formation
+    #[serde(default)]
+    position: Option<SpawnPosition>,
+    /// Tick on which this member enters the battle (0 = present from the start)
+    #[serde(default)]
+    arrive_tick: u64,
+    /// Arena edge a late arrival enters from (defaults to the team's own side)
+    #[serde(default)]
+    edge: Option<SpawnSide>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
+struct SpawnPosition {
+    x: i32,
+    y: i32,
+}
+
+fn parse_team(json: &str, label: &str) -> Result<TeamComposition, String> {
+    let data: TeamData = serde_json::from_str(json)
+        .map_err(|e| format!("Failed to parse team {}: {}", label, e))?;
+    
+    let mut team = match data {
+        TeamData::Members(members) => TeamComposition { formation: None, members, waves: Vec::new() },
+        TeamData::Composition(composition) => composition,
+    };
+    
+    // Unroll waves into individual members with their own arrival tick
+    for wave in std::mem::take(&mut team.waves) {
+        let every = wave.every.unwrap_or(0);
+        if wave.repeat > 1 && every == 0 {
+            return Err(format!(
+                "Team {} wave at tick {} repeats without an `every` interval",
+                label, wave.arrive_tick
+            ));
+        }
+        for round in 0..wave.repeat as u64 {
+            for member in &wave.members {
+                let mut member = member.clone();
+                member.arrive_tick = wave.arrive_tick + round * every;
+                member.edge = member.edge.or(wave.edge);
+                member.formation = member.formation.or(wave.formation);
+                team.members.push(member);
+            }
+        }
+    }
+    
+    Ok(team)
+}
+
+/// Assign a spawn cell to every member of a team, in member order.
+/// Members without an explicit position are grouped by species and formation;
+/// a member's own formation applies to its whole species group.
+fn plan_team_cells<R: Rng>(
+    planner: &mut SpawnPlanner,
+    rng: &mut R,
+    members: &[&TeamMemberData],
+    team_formation: Formation,
+    side: SpawnSide,
+) -> Result<Vec<(i32, i32)>, String> {
+    let formation_for = |species_id: &str| {
+        members
+            .iter()
+            .find(|m| m.species_id == species_id && m.formation.is_some())
+            .and_then(|m| m.formation)
+            .unwrap_or(team_formation)
+    };
+    
+    let mut cells: Vec<Option<(i32, i32)>> = members
+        .iter()
+        .map(|m| m.position.as_ref().map(|p| (p.x, p.y)))
+        .collect();
+    
+    // Group the remaining members, keeping first-appearance order for determinism
+    let mut groups: Vec<(Formation, Vec<usize>)> = Vec::new();
+    for (idx, member) in members.iter().enumerate() {
+        if cells[idx].is_some() {
+            continue;
+        }
+        let formation = formation_for(&member.species_id);
+        match groups.iter_mut().find(|(f, _)| *f == formation) {
+            Some((_, indices)) => indices.push(idx),
+            None => groups.push((formation, vec![idx])),
+        }
+    }
+    
+    for (formation, indices) in groups {
+        let planned = planner.plan(rng, formation, side, indices.len())?;
+        for (idx, cell) in indices.into_iter().zip(planned) {
+            cells[idx] = Some(cell);
+        }
+    }
+    
+    Ok(cells.into_iter().flatten().collect())
+}
+
+/// Scale a stat by a percent multiplier using integer math (100 = unchanged)
+fn scale_percent(value: i32, percent: u32) -> i32 {
+    (value as i64 * percent as i64 / 100) as i32
+}
+
+/// Convert a JSON stat multiplier (e.g. 1.25) to a whole percent once at
+/// init, so the simulation itself never multiplies by floats
+fn multiplier_to_percent(multiplier: f32) -> u32 {
+    (multiplier * 100.0).round().max(0.0) as u32
+}
+
+#[derive(Debug, Clone, Deserialize)]
 struct IndividualVariation {
     #[serde(default)]
     hp_multiplier: Option<f32>,
@@ -649,8 +1777,65 @@ struct IndividualVariation {
     injuries: Option<Vec<PreExistingInjury>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct PreExistingInjury {
     part_id: String,
     damage: i32,
 }
+
+/// Resolve a part's species attachments to the part instances it hangs
+/// from. Each of several matching parts hangs from its own counterpart
+/// (`claw_1` from `leg_1`); otherwise it hangs from every instance.
+fn attachment_instances(species: &Species, attachments: &[String], count: u32, index: u32) -> Vec<String> {
+    let mut instances = Vec::new();
+    for attachment in attachments {
+        let parent_count = species
+            .parts
+            .iter()
+            .find(|p| &p.part_id == attachment)
+            .map_or(1, |p| p.count);
+        
+        if parent_count <= 1 {
+            instances.push(attachment.clone());
+        } else if parent_count == count {
+            instances.push(format!("{}_{}", attachment, index));
+        } else {
+            instances.extend((0..parent_count).map(|i| format!("{}_{}", attachment, i)));
+        }
+    }
+    instances
+}
+
+/// Borrow two different actors mutably at once, wherever they are
+fn find_pair_mut<'a>(
+    team_a: &'a mut [Actor],
+    team_b: &'a mut [Actor],
+    first_id: u32,
+    second_id: u32,
+) -> Option<(&'a mut Actor, &'a mut Actor)> {
+    let mut first = None;
+    let mut second = None;
+    for actor in team_a.iter_mut().chain(team_b.iter_mut()) {
+        if actor.id == first_id {
+            first = Some(actor);
+        } else if actor.id == second_id {
+            second = Some(actor);
+        }
+    }
+    first.zip(second)
+}
+
+/// Directions to shove an occupant when pushed along `push`: straight ahead
+/// first, then fanning out to the sides, never back toward the pusher
+fn push_directions(push: (i32, i32)) -> Vec<(i32, i32)> {
+    const RING: [(i32, i32); 8] = [
+        (1, 0), (1, 1), (0, 1), (-1, 1),
+        (-1, 0), (-1, -1), (0, -1), (1, -1),
+    ];
+    
+    let idx = RING.iter().position(|&d| d == push).unwrap_or(0);
+    [0, 1, 7, 2, 6]
+        .iter()
+        .map(|offset| RING[(idx + offset) % RING.len()])
+        .collect()
+}
@@ -1,17 +1,45 @@
 use rand::Rng;
-use crate::sim::{Actor, Attack, Action};
+use crate::sim::{Actor, Attack, AttackType, Action, DamageProfile};
+use crate::sim::attack::DamageType;
+use crate::sim::pain::PainResolver;
+use crate::sim::critical::{CriticalKind, CriticalResolver};
+use crate::sim::venom::VenomResolver;
+use crate::sim::covering::CoveringResolver;
 use crate::events::BattleEvent;
-use crate::anatomy::part::Part;
+use crate::anatomy::part::{BodyZone, Part, Resistances};
+
+/// Movers at least this many times heavier than an enemy trample over it
+pub const TRAMPLE_MASS_RATIO: u32 = 20;
+/// Squared distance covering the 8 neighbouring cells (diagonals are 1² + 1²)
+pub const MELEE_RANGE_SQ: i32 = 2;
+/// Defenders this many times heavier than the attacker are hard to reach
+/// above the legs
+pub const LARGE_MASS_RATIO: u32 = 4;
+/// Defenders this many times heavier tower over the attacker, leaving
+/// little but legs and tails in reach
+pub const TOWERING_MASS_RATIO: u32 = 20;
+/// Most of an attack's accuracy a defender can cancel out by dodging
+pub const MAX_EVASION: u32 = 40;
+/// Each open wound bleeds this much less every tick as it clots
+pub const CLOT_PER_TICK: u32 = 1;
+/// Percent of its blood an actor can lose before it faints
+pub const FAINT_BLOOD_LOSS_PERCENT: u32 = 35;
+/// Percent of its blood an actor can lose before it dies
+pub const LETHAL_BLOOD_LOSS_PERCENT: u32 = 50;
+/// A shattered stone part throws debris worth this fraction of its health
+pub const SHATTER_DEBRIS_DIVISOR: i32 = 3;
 
 pub struct CombatResolver;
 
 impl CombatResolver {
-    /// Resolve an attack action
+    /// Resolve an attack action. `flankers` counts the attacker's allies
+    /// also pressing the defender, which makes critical hits likelier.
     pub fn resolve_attack<R: Rng>(
         rng: &mut R,
         attacker: &mut Actor,
         defender: &mut Actor,
         attack: &Attack,
+        flankers: u32,
     ) -> Vec<BattleEvent> {
         let mut events = Vec::new();
         
@@ -24,7 +52,8 @@ impl CombatResolver {
         // Consume stamina
         attacker.stamina = attacker.stamina.saturating_sub(attack.stamina_cost);
         
-        // Hit roll
+        // Hit roll. Rolls past the attack's accuracy miss outright; rolls in
+        // the top slice the defender's evasion claims are dodged.
         let hit_roll = rng.gen_range(0..100);
         if hit_roll >= attack.accuracy {
             // Miss!
@@ -35,6 +64,14 @@ impl CombatResolver {
             });
             return events;
         }
+        let evasion = Self::evasion(attacker, defender);
+        if hit_roll + evasion >= attack.accuracy {
+            events.push(BattleEvent::Dodge {
+                attacker_id: attacker.id,
+                defender_id: defender.id,
+            });
+            return events;
+        }
         
         // Select target part based on hit_weight
         if defender.parts.is_empty() {
@@ -42,142 +79,645 @@ impl CombatResolver {
             return events;
         }
         
-        let target_part = Self::select_target_part(rng, &defender.parts);
+        let target_part = Self::select_target_part(rng, attacker, defender);
         let target_part_id = target_part.part_id.clone();
         
-        // Calculate damage
-        let base_damage = attack.damage.base_damage;
-        let armor = target_part.armor;
+        // Criticals slip past the armor entirely
+        let critical = CriticalResolver::roll(rng, attacker, attack, target_part, flankers);
+        let critical_event = critical.as_ref().map(|hit| BattleEvent::Critical {
+            attacker_id: attacker.id,
+            defender_id: defender.id,
+            part_id: target_part_id.clone(),
+            kind: hit.kind,
+            organ: hit.organ.clone(),
+            flavor: CriticalResolver::flavor(hit, attack, target_part),
+        });
+        
+        // Blows that only just got past the dodge glance off armor plates
+        let margin = attack.accuracy - (hit_roll + evasion);
+        if critical.is_none() && CoveringResolver::deflects(target_part, margin, attack.damage.armor_penetration) {
+            events.push(BattleEvent::Deflect {
+                attacker_id: attacker.id,
+                defender_id: defender.id,
+                part_id: target_part_id,
+            });
+            return events;
+        }
+        
+        // Calculate damage: resistances scale each damage type, then armor soaks the rest
+        let (damage, blunt_damage) = Self::resisted_damage(&attack.damage, &target_part.resistances);
+        let armor = target_part.current_armor();
         let penetration = attack.damage.armor_penetration;
         
-        let effective_armor = (armor - penetration).max(0);
-        let final_damage = (base_damage - effective_armor).max(1);
+        let effective_armor = if critical.is_some() { 0 } else { (armor - penetration).max(0) };
+        let mut final_damage = (damage - effective_armor).max(1);
+        if let Some(hit) = &critical {
+            final_damage = CriticalResolver::damage(hit, final_damage);
+        }
+        
+        // Armor stops cuts and punctures but only cushions blunt force; half
+        // of what it soaked still bruises the vital parts underneath
+        let bruise = blunt_damage.min(effective_armor) / 2;
+        let bruised = Self::bruise_target(&defender.parts, target_part).map(|p| (p.part_id.clone(), p.is_stone()));
+        
+        // Stone feels nothing, so blows to it neither hurt nor shake morale
+        let felt = if target_part.is_stone() { 0 } else { final_damage };
+        let felt_bruise = match bruised {
+            Some((_, false)) => bruise,
+            _ => 0,
+        };
         
         // Morale drop from taking damage (before applying damage to parts)
-        let morale_loss = (final_damage as u32 / 3).max(1);
-        defender.reduce_morale(morale_loss);
+        if felt > 0 {
+            let morale_loss = (felt as u32 / 3).max(1);
+            defender.reduce_morale(morale_loss);
+        }
         
         // Apply damage to part
-        let part_destroyed = if let Some(part) = defender.parts.iter_mut().find(|p| p.part_id == target_part_id) {
-            part.hp -= final_damage;
-            
+        let part_destroyed = if defender.parts.iter().any(|p| p.part_id == target_part_id) {
             events.push(BattleEvent::Hit {
                 attacker_id: attacker.id,
                 defender_id: defender.id,
-                part_id: part.part_id.clone(),
+                part_id: target_part_id.clone(),
                 damage: final_damage as u32,
                 attack_name: attack.display_name.clone(),
             });
+            events.extend(critical_event);
             
-            part.hp <= 0
+            let (destroyed, wound_events) = Self::wound_part(defender, &target_part_id, final_damage);
+            events.extend(wound_events);
+            destroyed || critical.as_ref().is_some_and(|hit| hit.kind == CriticalKind::Sever)
         } else {
             false
         };
         
+        let wound = felt + felt_bruise;
+        
         // Handle part destruction
         if part_destroyed {
-            // Check if part should bleed
-            let should_sever = attack.damage.is_sharp;
+            // Only mostly-edged attacks cut a destroyed part clean off
+            events.extend(Self::destroy_part(rng, defender, &target_part_id, attack.damage.can_sever()));
+        } else {
+            // Check for bleeding
+            let bleed_chance = defender
+                .parts
+                .iter()
+                .find(|p| p.part_id == target_part_id)
+                .map_or(0, |p| CoveringResolver::bleed_chance(p, attack.damage.bleed_chance));
+            if bleed_chance > 0 && rng.gen_range(0..100) < bleed_chance {
+                events.extend(Self::open_wound(defender, &target_part_id, final_damage));
+            }
             
-            if should_sever {
-                events.push(BattleEvent::Sever {
-                    actor_id: defender.id,
-                    part_id: target_part_id.clone(),
-                    gib_char: '*',
-                    x: defender.x,
-                    y: defender.y,
-                });
-                
-                // Severe morale drop from losing a body part
-                defender.reduce_morale(15);
+            if let Some(organ) = critical.as_ref().and_then(|hit| hit.organ.as_deref()) {
+                events.extend(CriticalResolver::strike_organ(defender, &target_part_id, organ));
+            }
+        }
+        
+        // Blunt force carried through the armor, unless the blow already
+        // took the bruised part off
+        if bruise > 0 {
+            if let Some((bruised_id, _)) = bruised {
+                events.extend(Self::bruise_part(rng, attacker.id, defender, &bruised_id, bruise, attack));
+            }
+        }
+        
+        // Fangs that got through the armor inject their venom
+        if attack.damage.venom > 0 && damage > effective_armor {
+            events.extend(VenomResolver::envenom(defender, attack.damage.venom));
+        }
+        
+        events.extend(PainResolver::inflict(defender, wound));
+        
+        events
+    }
+    
+    /// Percent of hit chance the defender takes away by dodging. Quick,
+    /// small, fresh defenders dodge best; limping or tired ones less, and
+    /// held or helpless ones not at all.
+    pub fn evasion(attacker: &Actor, defender: &Actor) -> u32 {
+        if defender.is_prone() || defender.is_incapacitated() || defender.grappled_by.is_some() {
+            return 0;
+        }
+        
+        let mut evasion = defender.speed * 2 * defender.function_percent("locomotion") / 100;
+        
+        // Slipping under a much bigger attacker's blows
+        if Self::outweighs(attacker.mass_g, defender.mass_g, TOWERING_MASS_RATIO) {
+            evasion += 10;
+        } else if Self::outweighs(attacker.mass_g, defender.mass_g, LARGE_MASS_RATIO) {
+            evasion += 5;
+        }
+        
+        if defender.defending {
+            evasion += 10;
+        }
+        
+        if defender.stamina < defender.max_stamina / 4 {
+            evasion /= 2;
+        }
+        
+        evasion.min(MAX_EVASION)
+    }
+    
+    /// Damage after each type is scaled by the part's resistances, and how
+    /// much of that is blunt
+    pub fn resisted_damage(profile: &DamageProfile, resistances: &Resistances) -> (i32, i32) {
+        let scaled = |damage_type: DamageType| {
+            profile.base_damage as i64
+                * profile.share(damage_type) as i64
+                * resistances.against(damage_type) as i64
+        };
+        let edged = scaled(DamageType::Pierce) + scaled(DamageType::Slash);
+        let blunt = scaled(DamageType::Blunt);
+        
+        (((edged + blunt) / 10_000) as i32, (blunt / 10_000) as i32)
+    }
+    
+    /// The vital part that absorbs blunt force through a struck part: a vital
+    /// part it is attached to (a shell over the torso), or the struck part
+    /// itself if it is vital
+    fn bruise_target<'a>(parts: &'a [Part], struck: &'a Part) -> Option<&'a Part> {
+        struck.attachments
+            .iter()
+            .find_map(|base_id| parts.iter().find(|p| p.is_instance_of(base_id) && p.has_tag("vital")))
+            .or_else(|| struck.has_tag("vital").then_some(struck))
+    }
+    
+    /// Apply bruising to a part, destroying it (without severing) if it gives out
+    fn bruise_part<R: Rng>(
+        rng: &mut R,
+        attacker_id: u32,
+        defender: &mut Actor,
+        part_id: &str,
+        bruise: i32,
+        attack: &Attack,
+    ) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if !defender.parts.iter().any(|p| p.part_id == part_id) {
+            return events;
+        }
+        
+        events.push(BattleEvent::Hit {
+            attacker_id,
+            defender_id: defender.id,
+            part_id: part_id.to_string(),
+            damage: bruise as u32,
+            attack_name: format!("{} (bruise)", attack.display_name),
+        });
+        
+        let (destroyed, wound_events) = Self::wound_part(defender, part_id, bruise);
+        events.extend(wound_events);
+        
+        if destroyed {
+            events.extend(Self::destroy_part(rng, defender, part_id, false));
+        }
+        
+        events
+    }
+    
+    /// Take damage off a part and report it sinking to a worse wound level.
+    /// Returns whether the part was destroyed, which the caller handles.
+    pub fn wound_part(actor: &mut Actor, part_id: &str, damage: i32) -> (bool, Vec<BattleEvent>) {
+        let mut events = Vec::new();
+        let was_limping = actor.is_limping();
+        
+        let (before, after, destroyed) = match actor.parts.iter_mut().find(|p| p.part_id == part_id) {
+            Some(part) => {
+                let before = part.wound_level();
+                part.hp -= damage;
+                (before, part.wound_level(), part.hp <= 0)
             }
+            None => return (false, events),
+        };
+        
+        if destroyed || after == before {
+            return (destroyed, events);
+        }
+        
+        events.push(BattleEvent::Wound {
+            actor_id: actor.id,
+            part_id: part_id.to_string(),
+            level: after,
+        });
+        
+        if !was_limping && actor.is_limping() {
+            events.push(BattleEvent::StatusChange {
+                actor_id: actor.id,
+                status: "limping".to_string(),
+                active: true,
+            });
+        }
+        
+        (false, events)
+    }
+    
+    /// Remove a destroyed part from an actor and apply the consequences:
+    /// optional sever (with a gib flung nearby), capability loss, crash
+    /// landing for flyers and death
+    pub fn destroy_part<R: Rng>(
+        rng: &mut R,
+        defender: &mut Actor,
+        part_id: &str,
+        sever: bool,
+    ) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        let was_alive = defender.is_alive();
+        
+        let (gib_char, anchored, ethereal, stone, max_hp) = match defender.parts.iter().find(|p| p.part_id == part_id) {
+            Some(part) => (part.gib_glyph(), !part.attachments.is_empty(), part.is_ethereal(), part.is_stone(), part.max_hp),
+            None => return events,
+        };
+        
+        // Shattered stone breaks off whatever hung from it, as a sever would
+        let sever = sever || stone;
+        
+        if ethereal {
+            // Ethereal parts fade away instead of leaving a gib or a stump
+            events.push(BattleEvent::Fade {
+                actor_id: defender.id,
+                part_id: part_id.to_string(),
+            });
+        } else if stone {
+            // Stone bursts into debris that pelts everyone close by
+            events.push(BattleEvent::Shatter {
+                actor_id: defender.id,
+                part_id: part_id.to_string(),
+                gib_char,
+                debris: (max_hp / SHATTER_DEBRIS_DIVISOR).max(1) as u32,
+                x: defender.x,
+                y: defender.y,
+            });
+        } else if sever {
+            // The stump bleeds from whatever the part was attached to
+            events.extend(Self::open_stump(defender, part_id));
             
-            // Remove the part and apply effects
-            defender.remove_part(&target_part_id);
+            // Severed parts fly off to a nearby cell
+            events.push(BattleEvent::Sever {
+                actor_id: defender.id,
+                part_id: part_id.to_string(),
+                gib_char,
+                x: defender.x + rng.gen_range(-1..=1),
+                y: defender.y + rng.gen_range(-1..=1),
+            });
             
-            // Check for death
-            if !defender.is_alive() {
-                events.push(BattleEvent::Death {
-                    actor_id: defender.id,
-                    x: defender.x,
-                    y: defender.y,
-                });
-            }
-        } else {
-            // Check for bleeding
-            if attack.damage.bleed_chance > 0.0 {
-                let bleed_roll: f32 = rng.gen();
-                if bleed_roll < attack.damage.bleed_chance {
-                    // Increase bleed rate on the part
-                    if let Some(part) = defender.parts.iter_mut().find(|p| p.part_id == target_part_id) {
-                        part.bleed_rate += 1;
-                        
-                        events.push(BattleEvent::Bleed {
-                            actor_id: defender.id,
-                            amount: 1,
-                        });
-                    }
+            // Severe morale drop from losing a body part
+            defender.reduce_morale(15);
+        }
+        
+        // Remove the part and apply effects
+        let was_airborne = defender.airborne;
+        defender.remove_part(part_id);
+        
+        // Everything that hung from a severed limb comes off with it. A
+        // severed root part (the torso) leaves the rest as the body.
+        if sever && anchored {
+            for detached_id in defender.disconnected_parts() {
+                match defender.parts.iter().find(|p| p.part_id == detached_id) {
+                    Some(part) if part.is_ethereal() => events.push(BattleEvent::Fade {
+                        actor_id: defender.id,
+                        part_id: detached_id.clone(),
+                    }),
+                    Some(part) => events.push(BattleEvent::Sever {
+                        actor_id: defender.id,
+                        part_id: detached_id.clone(),
+                        gib_char: part.gib_glyph(),
+                        x: defender.x + rng.gen_range(-1..=1),
+                        y: defender.y + rng.gen_range(-1..=1),
+                    }),
+                    None => {}
                 }
+                defender.remove_part(&detached_id);
             }
         }
         
+        // Losing the last flight part mid-air sends the defender crashing down
+        if was_airborne && defender.is_alive() && !defender.can_fly() {
+            let fall_damage = defender.crash_land();
+            events.push(BattleEvent::Grounded {
+                actor_id: defender.id,
+                fall_damage,
+                x: defender.x,
+                y: defender.y,
+            });
+        }
+        
+        // Check for death, once, on the part that killed
+        if was_alive && !defender.is_alive() {
+            events.push(BattleEvent::Death {
+                actor_id: defender.id,
+                x: defender.x,
+                y: defender.y,
+            });
+        }
+        
         events
     }
     
-    /// Select a target part based on hit_weight distribution
-    fn select_target_part<'a, R: Rng>(rng: &mut R, parts: &'a [Part]) -> &'a Part {
-        let total_weight: u32 = parts.iter().map(|p| p.hit_weight).sum();
+    /// Select a target part among the parts the attacker can reach, weighted
+    /// by how exposed each one is to this attacker
+    pub fn select_target_part<'a, R: Rng>(rng: &mut R, attacker: &Actor, defender: &'a Actor) -> &'a Part {
+        let parts = &defender.parts;
+        let mut candidates: Vec<(&Part, u32)> = parts
+            .iter()
+            .filter(|p| Self::can_reach_part(p, attacker.airborne, defender.airborne))
+            .map(|p| (p, Self::exposure(p, attacker, defender)))
+            .collect();
+        if candidates.is_empty() {
+            candidates = parts.iter().map(|p| (p, Self::exposure(p, attacker, defender))).collect();
+        }
+        
+        let total_weight: u32 = candidates.iter().map(|(_, weight)| weight).sum();
         let mut roll = rng.gen_range(0..total_weight);
         
-        for part in parts {
-            if roll < part.hit_weight {
+        for (part, weight) in &candidates {
+            if roll < *weight {
                 return part;
             }
-            roll -= part.hit_weight;
+            roll -= weight;
         }
         
         // Fallback (shouldn't happen)
-        &parts[0]
+        candidates[0].0
+    }
+    
+    /// Hit weight of a part against this attacker: scaled by how far up the
+    /// body it can reach, halved behind each armor part covering it and
+    /// halved again for the head and vitals of a defender on guard
+    pub fn exposure(part: &Part, attacker: &Actor, defender: &Actor) -> u32 {
+        let mut weight = part.hit_weight * Self::zone_reach_percent(part.zone(), attacker, defender);
+        
+        let covers = defender
+            .parts
+            .iter()
+            .filter(|c| c.has_tag("armor") && c.part_id != part.part_id && c.attachments.contains(&part.part_id))
+            .count();
+        weight >>= covers.min(8);
+        
+        if defender.defending && (part.zone() == BodyZone::High || part.has_tag("vital")) {
+            weight /= 2;
+        }
+        
+        weight.max(1)
+    }
+    
+    /// Percent of normal exposure for a body zone given the attacker's relative
+    /// size. Small attackers mostly reach the legs and tails of big defenders;
+    /// big attackers strike down at small ones. A prone defender or a flyer
+    /// diving from above can reach anything.
+    pub fn zone_reach_percent(zone: BodyZone, attacker: &Actor, defender: &Actor) -> u32 {
+        if defender.is_prone() || (attacker.airborne && !defender.airborne) {
+            return 100;
+        }
+        
+        let (attacker_mass, defender_mass) = (attacker.mass_g, defender.mass_g);
+        let towering = Self::outweighs(defender_mass, attacker_mass, TOWERING_MASS_RATIO);
+        let large = Self::outweighs(defender_mass, attacker_mass, LARGE_MASS_RATIO);
+        let small = Self::outweighs(attacker_mass, defender_mass, LARGE_MASS_RATIO);
+        
+        match zone {
+            BodyZone::Low if small => 50,
+            BodyZone::Low => 100,
+            BodyZone::Mid if towering => 20,
+            BodyZone::Mid if large => 50,
+            BodyZone::Mid => 100,
+            BodyZone::High if towering => 5,
+            BodyZone::High if large => 20,
+            BodyZone::High if small => 150,
+            BodyZone::High => 100,
+        }
+    }
+    
+    /// Check if a part can be reached given the height difference between
+    /// attacker and defender
+    pub fn can_reach_part(part: &Part, attacker_airborne: bool, defender_airborne: bool) -> bool {
+        match (attacker_airborne, defender_airborne) {
+            // Attacking a flyer from the ground only reaches its underside
+            (false, true) => part.zone() != BodyZone::High,
+            // Diving attacks strike from above and can't reach the legs
+            (true, false) => part.zone() != BodyZone::Low,
+            _ => true,
+        }
     }
     
-    /// Apply bleeding damage to an actor
+    /// Bleed rate a wound opens: the part's base rate, scaled up by how
+    /// much of the part the blow took
+    pub fn wound_bleed_rate(base_bleed_rate: u32, damage: i32, part_max_hp: i32) -> u32 {
+        let max_hp = part_max_hp.max(1);
+        let severity = (damage.clamp(0, max_hp) * 6 / max_hp) as u32;
+        base_bleed_rate * (2 + severity)
+    }
+    
+    /// Open a bleeding wound on a part
+    pub fn open_wound(actor: &mut Actor, part_id: &str, damage: i32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if let Some(part) = actor.parts.iter_mut().find(|p| p.part_id == part_id) {
+            let opened = Self::wound_bleed_rate(part.base_bleed_rate, damage, part.max_hp);
+            if opened > 0 && part.bleeds() {
+                part.bleed_rate += opened;
+                events.push(BattleEvent::Bleed {
+                    actor_id: actor.id,
+                    amount: opened,
+                });
+            }
+        }
+        
+        events
+    }
+    
+    /// A severed part leaves the part it hung from bleeding as badly as a
+    /// wound that took it clean through
+    fn open_stump(actor: &mut Actor, part_id: &str) -> Vec<BattleEvent> {
+        let (base_bleed_rate, parent_id) = match actor.parts.iter().find(|p| p.part_id == part_id) {
+            Some(part) => (part.base_bleed_rate, part.attachments.first().cloned()),
+            None => return Vec::new(),
+        };
+        
+        let parent = parent_id.and_then(|id| actor.parts.iter_mut().find(|p| p.is_instance_of(&id)));
+        match parent {
+            Some(parent) if parent.bleeds() => {
+                let opened = Self::wound_bleed_rate(base_bleed_rate, 1, 1);
+                parent.bleed_rate += opened;
+                vec![BattleEvent::Bleed {
+                    actor_id: actor.id,
+                    amount: opened,
+                }]
+            }
+            _ => Vec::new(),
+        }
+    }
+    
+    /// Drain blood from every open wound, then let the wounds clot a little.
+    /// Losing too much blood makes the actor faint, then kills it.
     pub fn apply_bleeding(actor: &mut Actor) -> Vec<BattleEvent> {
         let mut events = Vec::new();
         let total_bleed = actor.get_total_bleed_rate();
         
-        if total_bleed > 0 {
-            let bleed_damage = total_bleed;
-            actor.hp -= bleed_damage as i32;
-            
-            events.push(BattleEvent::Bleed {
+        if total_bleed == 0 {
+            return events;
+        }
+        
+        let lost = (actor.max_blood_ul as u64 * total_bleed as u64 / 1000).max(1) as u32;
+        actor.blood_ul = actor.blood_ul.saturating_sub(lost);
+        
+        events.push(BattleEvent::Bleed {
+            actor_id: actor.id,
+            amount: total_bleed,
+        });
+        
+        for part in actor.parts.iter_mut() {
+            part.bleed_rate = part.bleed_rate.saturating_sub(CLOT_PER_TICK);
+        }
+        
+        let lost_percent = actor.blood_lost_percent();
+        if lost_percent >= LETHAL_BLOOD_LOSS_PERCENT {
+            actor.hp = 0;
+            actor.alive = false;
+            events.push(BattleEvent::Death {
                 actor_id: actor.id,
-                amount: bleed_damage,
+                x: actor.x,
+                y: actor.y,
             });
-            
-            if actor.hp <= 0 {
-                actor.hp = 0;
-                actor.alive = false;
-                events.push(BattleEvent::Death {
-                    actor_id: actor.id,
-                    x: actor.x,
-                    y: actor.y,
-                });
+        } else if lost_percent >= FAINT_BLOOD_LOSS_PERCENT && !actor.fainted {
+            actor.fainted = true;
+            actor.energy = 0;
+            events.push(BattleEvent::StatusChange {
+                actor_id: actor.id,
+                status: "fainted".to_string(),
+                active: true,
+            });
+            events.extend(Self::fall_from_sky(actor));
+        }
+        
+        events
+    }
+    
+    /// Stone debris from a shattered part strikes a random part of a nearby
+    /// actor as a blunt blow
+    pub fn debris_hit<R: Rng>(rng: &mut R, source_id: u32, actor: &mut Actor, debris: u32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if !actor.is_alive() || actor.parts.is_empty() {
+            return events;
+        }
+        
+        let total_weight: u32 = actor.parts.iter().map(|p| p.hit_weight.max(1)).sum();
+        let mut roll = rng.gen_range(0..total_weight);
+        let mut index = 0;
+        for (i, part) in actor.parts.iter().enumerate() {
+            let weight = part.hit_weight.max(1);
+            if roll < weight {
+                index = i;
+                break;
             }
+            roll -= weight;
         }
         
+        let part = &actor.parts[index];
+        let part_id = part.part_id.clone();
+        let damage = (debris as i32 * part.resistances.blunt as i32 / 100 - part.current_armor()).max(1);
+        let felt = if part.is_stone() { 0 } else { damage };
+        
+        events.push(BattleEvent::Hit {
+            attacker_id: source_id,
+            defender_id: actor.id,
+            part_id: part_id.clone(),
+            damage: damage as u32,
+            attack_name: "Stone debris".to_string(),
+        });
+        
+        let (destroyed, wound_events) = Self::wound_part(actor, &part_id, damage);
+        events.extend(wound_events);
+        if destroyed {
+            events.extend(Self::destroy_part(rng, actor, &part_id, false));
+        }
+        
+        events.extend(PainResolver::inflict(actor, felt));
+        
         events
     }
     
-    /// Calculate distance between two positions
-    pub fn distance(x1: i32, y1: i32, x2: i32, y2: i32) -> f32 {
-        let dx = (x2 - x1) as f32;
-        let dy = (y2 - y1) as f32;
-        (dx * dx + dy * dy).sqrt()
+    /// An actor that collapses mid-air crashes to the ground
+    pub fn fall_from_sky(actor: &mut Actor) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if !actor.airborne {
+            return events;
+        }
+        
+        let fall_damage = actor.crash_land();
+        events.push(BattleEvent::Grounded {
+            actor_id: actor.id,
+            fall_damage,
+            x: actor.x,
+            y: actor.y,
+        });
+        if !actor.is_alive() {
+            events.push(BattleEvent::Death {
+                actor_id: actor.id,
+                x: actor.x,
+                y: actor.y,
+            });
+        }
+        
+        events
     }
     
-    /// Check if an attack is in range
-    pub fn is_in_range(attacker: &Actor, defender: &Actor, range: f32) -> bool {
-        Self::distance(attacker.x, attacker.y, defender.x, defender.y) <= range
+    /// Percent chance for a mover to shove an occupant out of its cell,
+    /// proportional to the mover's share of their combined mass
+    pub fn bump_chance(bumper_mass: u32, bumped_mass: u32) -> u32 {
+        let total = bumper_mass as u64 + bumped_mass as u64;
+        if total == 0 {
+            return 50;
+        }
+        ((bumper_mass as u64 * 100 / total) as u32).clamp(5, 95)
+    }
+    
+    /// Cells a defender is thrown back by a blunt bash or ram (0 = no knockback).
+    /// The attacker must be at least twice as heavy.
+    pub fn knockback_distance(attack: &Attack, attacker_mass: u32, defender_mass: u32) -> i32 {
+        if !matches!(attack.attack_type, AttackType::Bash | AttackType::Ram) {
+            return 0;
+        }
+        
+        if Self::outweighs(attacker_mass, defender_mass, 16) {
+            3
+        } else if Self::outweighs(attacker_mass, defender_mass, 4) {
+            2
+        } else if Self::outweighs(attacker_mass, defender_mass, 2) {
+            1
+        } else {
+            0
+        }
+    }
+    
+    /// Check if one mass is at least `ratio` times another
+    pub fn outweighs(mass: u32, other_mass: u32, ratio: u32) -> bool {
+        mass as u64 >= other_mass.max(1) as u64 * ratio as u64
+    }
+    
+    /// Check if a mover is heavy enough to trample an enemy in its path
+    pub fn can_trample(mover_mass: u32, victim_mass: u32) -> bool {
+        Self::outweighs(mover_mass, victim_mass, TRAMPLE_MASS_RATIO)
+    }
+    
+    /// Damage dealt to a trampled actor
+    pub fn trample_damage(victim: &Actor) -> u32 {
+        (victim.max_hp / 2).max(1) as u32
+    }
+    
+    /// Squared distance between two positions. Compare against squared
+    /// ranges so no square roots (or floats) are involved.
+    pub fn distance_sq(x1: i32, y1: i32, x2: i32, y2: i32) -> i32 {
+        let dx = x2 - x1;
+        let dy = y2 - y1;
+        dx * dx + dy * dy
+    }
+    
+    /// Check if an attack is in range, given the squared range
+    pub fn is_in_range(attacker: &Actor, defender: &Actor, range_sq: i32) -> bool {
+        Self::distance_sq(attacker.x, attacker.y, defender.x, defender.y) <= range_sq
     }
 }
 
@@ -186,10 +726,11 @@ mod tests {
     use super::*;
     use rand::rngs::SmallRng;
     use rand_seeder::Seeder;
-    use crate::anatomy::part::Part;
+    use crate::sim::attack::{AttackTemplate, DamageShare};
+    use crate::anatomy::part::WoundLevel;
     
     fn create_test_actor(id: u32) -> Actor {
-        let mut actor = Actor::new(id, "test".to_string(), 'T', 0, 0, 0);
+        let mut actor = Actor::new(id, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
         actor.hp = 50;
         actor.max_hp = 50;
         actor.stamina = 100;
@@ -206,7 +747,11 @@ mod tests {
             max_hp: 20,
             armor: 2,
             bleed_rate: 0,
+            base_bleed_rate: 0,
             hit_weight: 10,
+            resistances: Resistances::default(),
+            zone: None,
+            attacks_provided: None,
         });
         
         actor.parts.push(Part {
@@ -214,12 +759,16 @@ mod tests {
             display_name: "Head".to_string(),
             count: 1,
             attachments: vec!["torso".to_string()],
-            tags: vec!["brain".to_string(), "vital".to_string()],
+            tags: vec!["head".to_string(), "brain".to_string(), "vital".to_string()],
             hp: 15,
             max_hp: 15,
             armor: 0,
             bleed_rate: 0,
+            base_bleed_rate: 0,
             hit_weight: 5,
+            resistances: Resistances::default(),
+            zone: None,
+            attacks_provided: None,
         });
         
         actor
@@ -235,12 +784,13 @@ mod tests {
             damage: DamageProfile {
                 base_damage: 10,
                 armor_penetration: 1,
-                bleed_chance: 0.3,
-                is_sharp: true,
-                is_blunt: false,
+                bleed_chance: 30,
+                venom: 0,
+                types: vec![DamageShare { damage_type: DamageType::Slash, percent: 100 }],
             },
             accuracy: 70,
             stamina_cost: 10,
+            range: 1,
         }
     }
     
@@ -251,7 +801,7 @@ mod tests {
         let mut defender = create_test_actor(2);
         let attack = create_test_attack();
         
-        let events = CombatResolver::resolve_attack(&mut rng, &mut attacker, &mut defender, &attack);
+        let events = CombatResolver::resolve_attack(&mut rng, &mut attacker, &mut defender, &attack, 0);
         
         // Should have consumed stamina
         assert!(attacker.stamina < 100);
@@ -261,21 +811,493 @@ mod tests {
     }
     
     #[test]
-    fn test_bleeding_damage() {
+    fn test_bleeding_drains_blood_not_hp() {
         let mut actor = create_test_actor(1);
-        actor.parts[0].bleed_rate = 2;
-        actor.parts[1].bleed_rate = 1;
+        actor.parts[0].bleed_rate = 20;
+        actor.parts[1].bleed_rate = 10;
         
         let initial_hp = actor.hp;
         let events = CombatResolver::apply_bleeding(&mut actor);
         
-        assert_eq!(actor.hp, initial_hp - 3);
+        // 30 thousandths of the blood this tick, and each wound clots a little
+        assert_eq!(actor.hp, initial_hp);
+        assert_eq!(actor.blood_ul, actor.max_blood_ul - actor.max_blood_ul * 30 / 1000);
+        assert_eq!(actor.get_total_bleed_rate(), 30 - 2 * CLOT_PER_TICK);
         assert!(!events.is_empty());
     }
     
+    #[test]
+    fn test_wounds_bleed_by_part_rate_and_severity() {
+        assert_eq!(CombatResolver::wound_bleed_rate(0, 10, 10), 0);
+        assert_eq!(CombatResolver::wound_bleed_rate(3, 1, 40), 6);
+        assert_eq!(CombatResolver::wound_bleed_rate(3, 20, 40), 15);
+        assert_eq!(CombatResolver::wound_bleed_rate(3, 80, 40), 24);
+    }
+    
+    #[test]
+    fn test_blood_loss_faints_then_kills() {
+        let mut actor = create_test_actor(1);
+        actor.airborne = true;
+        actor.parts[0].bleed_rate = 200;
+        
+        // 20% lost: still standing
+        CombatResolver::apply_bleeding(&mut actor);
+        assert!(!actor.fainted);
+        
+        // Another ~20%: past the faint threshold, and the flyer drops
+        let events = CombatResolver::apply_bleeding(&mut actor);
+        assert!(actor.fainted && actor.is_incapacitated());
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::StatusChange { status, .. } if status == "fainted")));
+        assert!(!actor.airborne);
+        assert!(actor.is_alive());
+        
+        let events = CombatResolver::apply_bleeding(&mut actor);
+        assert!(!actor.is_alive());
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Death { .. })));
+    }
+    
+    #[test]
+    fn test_severing_last_wing_grounds_flyer() {
+        let mut rng: SmallRng = Seeder::from(12345u64).make_rng();
+        let mut attacker = create_test_actor(1);
+        attacker.airborne = true;
+        
+        let mut defender = Actor::new(2, "test".to_string(), 'T', "white".to_string(), 1, 0, 0);
+        defender.airborne = true;
+        let mut wing = Part::tagged("wing", &["wing", "flight"]);
+        wing.hp = 1;
+        wing.max_hp = 1;
+        defender.parts.push(wing);
+        
+        defender.speed = 0; // Too slow to dodge, so the hit always lands
+        let mut attack = create_test_attack();
+        attack.accuracy = 100;
+        
+        let events = CombatResolver::resolve_attack(&mut rng, &mut attacker, &mut defender, &attack, 0);
+        
+        assert!(!defender.airborne);
+        assert!(defender.hp < defender.max_hp, "Fall should deal damage");
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Grounded { actor_id: 2, .. })));
+    }
+    
+    #[test]
+    fn test_height_limits_reachable_parts() {
+        let actor = create_test_actor(1);
+        let head = actor.parts.iter().find(|p| p.part_id == "head").unwrap();
+        let torso = actor.parts.iter().find(|p| p.part_id == "torso").unwrap();
+        
+        // Grounded attackers can't reach a flyer's head, but can reach its body
+        assert!(!CombatResolver::can_reach_part(head, false, true));
+        assert!(CombatResolver::can_reach_part(torso, false, true));
+        assert!(CombatResolver::can_reach_part(head, true, true));
+    }
+    
+    #[test]
+    fn test_mass_drives_bumps_and_knockback() {
+        // Equal masses keep the old coin flip; a dragon shoves a chicken almost surely
+        assert_eq!(CombatResolver::bump_chance(10_000, 10_000), 50);
+        assert_eq!(CombatResolver::bump_chance(800_000, 2_500), 95);
+        assert_eq!(CombatResolver::bump_chance(2_500, 800_000), 5);
+        
+        let mut bash = create_test_attack();
+        bash.attack_type = AttackType::Bash;
+        assert_eq!(CombatResolver::knockback_distance(&bash, 800_000, 2_500), 3);
+        assert_eq!(CombatResolver::knockback_distance(&bash, 30_000, 25_000), 0);
+        
+        // Only blunt bashes and rams knock defenders back
+        let scratch = create_test_attack();
+        assert_eq!(CombatResolver::knockback_distance(&scratch, 800_000, 2_500), 0);
+        
+        assert!(CombatResolver::can_trample(800_000, 1));
+        assert!(!CombatResolver::can_trample(30_000, 25_000));
+    }
+    
+    #[test]
+    fn test_resistances_scale_each_damage_type() {
+        let stone = Resistances::from_tags(&["stone".to_string()]);
+        assert_eq!(stone, Resistances { pierce: 50, slash: 40, blunt: 120 });
+        
+        // Mixed profile: 60% pierce + 40% blunt of 20 damage
+        let mut bite = create_test_attack();
+        bite.damage.base_damage = 20;
+        bite.damage.types = vec![
+            DamageShare { damage_type: DamageType::Pierce, percent: 60 },
+            DamageShare { damage_type: DamageType::Blunt, percent: 40 },
+        ];
+        assert_eq!(CombatResolver::resisted_damage(&bite.damage, &Resistances::default()), (20, 8));
+        assert_eq!(CombatResolver::resisted_damage(&bite.damage, &stone), (15, 9));
+        assert!(bite.damage.can_sever());
+    }
+    
+    #[test]
+    fn test_blunt_bruises_vital_part_behind_armor() {
+        let mut rng: SmallRng = Seeder::from(5u64).make_rng();
+        let mut attacker = create_test_actor(1);
+        let mut defender = Actor::new(2, "turtle".to_string(), 'T', "green".to_string(), 1, 1, 0);
+        let mut torso = Part::tagged("torso", &["torso", "vital"]);
+        torso.hp = 30;
+        torso.max_hp = 30;
+        torso.hit_weight = 0;
+        defender.parts.push(torso);
+        let mut shell = Part::attached("shell", &["armor"], &["torso"]);
+        shell.hp = 50;
+        shell.max_hp = 50;
+        shell.armor = 15;
+        shell.hit_weight = 10;
+        defender.parts.push(shell);
+        
+        defender.speed = 0; // Too slow to dodge, so the hit always lands
+        let mut bash = create_test_attack();
+        bash.accuracy = 100;
+        bash.damage.base_damage = 20;
+        bash.damage.armor_penetration = 0;
+        bash.damage.types = vec![DamageShare { damage_type: DamageType::Blunt, percent: 100 }];
+        
+        let events = CombatResolver::resolve_attack(&mut rng, &mut attacker, &mut defender, &bash, 0);
+        
+        // The shell soaks 15 of 20; half of that reaches the torso underneath
+        let torso = defender.parts.iter().find(|p| p.part_id == "torso").unwrap();
+        assert_eq!(torso.hp, 30 - 7);
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Hit { part_id, damage: 7, .. } if part_id == "torso")));
+        
+        // Slashing the same shell leaves the torso alone
+        let mut slash = bash.clone();
+        slash.damage.types = vec![DamageShare { damage_type: DamageType::Slash, percent: 100 }];
+        attacker.stamina = 100;
+        CombatResolver::resolve_attack(&mut rng, &mut attacker, &mut defender, &slash, 0);
+        let torso = defender.parts.iter().find(|p| p.part_id == "torso").unwrap();
+        assert_eq!(torso.hp, 30 - 7);
+    }
+    
+    #[test]
+    fn test_killing_blow_reports_one_death_and_its_sever() {
+        let mut rng: SmallRng = Seeder::from(6u64).make_rng();
+        let mut attacker = create_test_actor(1);
+        
+        // 12 slash cuts through the helmet; 8 blunt bruises what lies under it
+        let mut axe = create_test_attack();
+        axe.accuracy = 100;
+        axe.damage.base_damage = 20;
+        axe.damage.armor_penetration = 0;
+        axe.damage.types = vec![
+            DamageShare { damage_type: DamageType::Slash, percent: 60 },
+            DamageShare { damage_type: DamageType::Blunt, percent: 40 },
+        ];
+        
+        // The head comes off while the bruise crushes the torso it sits on
+        let mut defender = Actor::new(2, "test".to_string(), 'T', "white".to_string(), 1, 1, 0);
+        defender.speed = 0;
+        let mut torso = Part::attached("torso", &["torso", "vital"], &[]);
+        torso.hp = 2;
+        torso.hit_weight = 0;
+        let mut head = Part::attached("head", &["head", "vital"], &["torso"]);
+        head.hp = 1;
+        head.armor = 10;
+        defender.parts.push(torso);
+        defender.parts.push(head);
+        
+        let events = CombatResolver::resolve_attack(&mut rng, &mut attacker, &mut defender, &axe, 0);
+        assert!(!defender.is_alive());
+        assert_eq!(events.iter().filter(|e| matches!(e, BattleEvent::Death { .. })).count(), 1);
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Sever { part_id, .. } if part_id == "head")));
+        
+        // A lone vital head is cut off, not bruised away first
+        let mut defender = Actor::new(3, "test".to_string(), 'T', "white".to_string(), 1, 1, 0);
+        defender.speed = 0;
+        let mut head = Part::attached("head", &["head", "vital"], &[]);
+        head.hp = 1;
+        head.armor = 10;
+        defender.parts.push(head);
+        
+        attacker.stamina = 100;
+        let events = CombatResolver::resolve_attack(&mut rng, &mut attacker, &mut defender, &axe, 0);
+        assert_eq!(events.iter().filter(|e| matches!(e, BattleEvent::Death { .. })).count(), 1);
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Sever { part_id, .. } if part_id == "head")));
+    }
+    
     #[test]
     fn test_distance_calculation() {
-        let dist = CombatResolver::distance(0, 0, 3, 4);
-        assert_eq!(dist, 5.0);
+        assert_eq!(CombatResolver::distance_sq(0, 0, 3, 4), 25);
+        
+        // All 8 neighbours are in melee range, two cells away is not
+        let a = create_test_actor(1);
+        let mut b = create_test_actor(2);
+        b.x = 1;
+        b.y = 1;
+        assert!(CombatResolver::is_in_range(&a, &b, MELEE_RANGE_SQ));
+        b.x = 2;
+        assert!(!CombatResolver::is_in_range(&a, &b, MELEE_RANGE_SQ));
+    }
+    
+    #[test]
+    fn test_wound_levels_worsen_and_mangled_leg_limps() {
+        let mut actor = create_test_actor(1);
+        actor.parts.push(Part::tagged("leg_0", &["leg", "locomotion"]));
+        actor.parts.push(Part::tagged("leg_1", &["leg", "locomotion"]));
+        
+        // 10 -> 8 HP: bruised, still walking normally
+        let (destroyed, events) = CombatResolver::wound_part(&mut actor, "leg_0", 2);
+        assert!(!destroyed);
+        assert!(matches!(&events[..], [BattleEvent::Wound { level: WoundLevel::Bruised, .. }]));
+        assert!(!actor.is_limping());
+        
+        // 8 -> 2 HP: mangled, now limping
+        let (_, events) = CombatResolver::wound_part(&mut actor, "leg_0", 6);
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Wound { level: WoundLevel::Mangled, .. })));
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::StatusChange { status, active: true, .. } if status == "limping")));
+        
+        // Slowed, but not immobilized
+        actor.speed = 10;
+        actor.energy = 0;
+        actor.gain_energy();
+        assert_eq!(actor.energy, 8 * crate::sim::actor::ENERGY_PER_SPEED);
+        
+        // Same level again: no new event
+        let (_, events) = CombatResolver::wound_part(&mut actor, "leg_0", 1);
+        assert!(events.is_empty());
+    }
+    
+    #[test]
+    fn test_mangled_jaw_bites_weaker_and_wounded_wing_tires() {
+        let table = AttackTemplate::load_test_table();
+        let mut actor = Actor::new(1, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        actor.mass_g = 64_000;
+        let mut jaw = Part::tagged("jaw", &["bite_weapon"]);
+        jaw.hp = 30;
+        jaw.max_hp = 30;
+        actor.parts.push(jaw);
+        actor.parts.push(Part::tagged("wing", &["wing", "flight"]));
+        
+        let healthy_bite = actor.get_available_attacks(&table)[0].damage.base_damage;
+        let healthy_flight = actor.flight_stamina_cost();
+        
+        // 8 of 30 HP left: the mangled jaw bites with half its force
+        actor.parts[0].hp = 8;
+        assert_eq!(actor.parts[0].wound_level(), WoundLevel::Mangled);
+        assert_eq!(healthy_bite, 13);
+        assert_eq!(actor.get_available_attacks(&table)[0].damage.base_damage, healthy_bite / 2);
+        
+        actor.parts[1].hp = 5;
+        assert_eq!(actor.parts[1].wound_level(), WoundLevel::Wounded);
+        assert!(actor.flight_stamina_cost() > healthy_flight);
+    }
+    
+    #[test]
+    fn test_severing_neck_takes_head_and_jaw_with_it() {
+        let mut rng: SmallRng = Seeder::from(3u64).make_rng();
+        let mut dragon = Actor::new(1, "dragon".to_string(), 'D', "red".to_string(), 0, 0, 0);
+        dragon.parts.push(Part::attached("torso", &["torso", "vital"], &[]));
+        dragon.parts.push(Part::attached("neck", &["neck"], &["torso"]));
+        dragon.parts.push(Part::attached("head", &["head", "vital"], &["neck"]));
+        dragon.parts.push(Part::attached("jaw", &["bite_weapon"], &["head"]));
+        // The mane hangs from both head and torso, so it stays on
+        dragon.parts.push(Part::attached("mane", &[], &["head", "torso"]));
+        
+        let events = CombatResolver::destroy_part(&mut rng, &mut dragon, "neck", true);
+        
+        let severed: Vec<&str> = events
+            .iter()
+            .filter_map(|e| match e {
+                BattleEvent::Sever { part_id, .. } => Some(part_id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(severed, vec!["neck", "head", "jaw"]);
+        assert!(!dragon.is_alive());
+        assert!(dragon.parts.iter().any(|p| p.part_id == "mane"));
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Death { .. })));
+    }
+    
+    #[test]
+    fn test_severing_leg_drops_only_its_own_claw() {
+        let mut rng: SmallRng = Seeder::from(4u64).make_rng();
+        let mut actor = Actor::new(1, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        actor.parts.push(Part::attached("torso", &["torso", "vital"], &[]));
+        actor.parts.push(Part::attached("leg_0", &["leg", "locomotion"], &["torso"]));
+        actor.parts.push(Part::attached("leg_1", &["leg", "locomotion"], &["torso"]));
+        actor.parts.push(Part::attached("claw_0", &["claw_weapon"], &["leg_0"]));
+        actor.parts.push(Part::attached("claw_1", &["claw_weapon"], &["leg_1"]));
+        
+        let events = CombatResolver::destroy_part(&mut rng, &mut actor, "leg_0", true);
+        
+        assert_eq!(events.iter().filter(|e| matches!(e, BattleEvent::Sever { .. })).count(), 2);
+        let remaining: Vec<&str> = actor.parts.iter().map(|p| p.part_id.as_str()).collect();
+        assert_eq!(remaining, vec!["torso", "leg_1", "claw_1"]);
+        assert!(actor.is_alive());
+        
+        // Crushing a leg without severing it leaves the claw where it is
+        let mut actor_b = actor.clone();
+        CombatResolver::destroy_part(&mut rng, &mut actor_b, "leg_1", false);
+        assert!(actor_b.parts.iter().any(|p| p.part_id == "claw_1"));
+    }
+    
+    fn create_quadruped(id: u32, mass_g: u32) -> Actor {
+        let mut actor = Actor::new(id, "test".to_string(), 'Q', "white".to_string(), 0, 0, 0);
+        actor.mass_g = mass_g;
+        actor.parts.push(Part::attached("torso", &["torso", "vital"], &[]));
+        actor.parts.push(Part::attached("head", &["head", "vital"], &["torso"]));
+        actor.parts.push(Part::attached("leg", &["leg", "locomotion"], &["torso"]));
+        actor.parts.push(Part::attached("tail", &["tail"], &["torso"]));
+        for part in actor.parts.iter_mut() {
+            part.hit_weight = 4;
+        }
+        actor
+    }
+    
+    fn count_zone_hits(attacker: &Actor, defender: &Actor, zone: BodyZone) -> u32 {
+        let mut rng: SmallRng = Seeder::from(5u64).make_rng();
+        (0..1000)
+            .filter(|_| CombatResolver::select_target_part(&mut rng, attacker, defender).zone() == zone)
+            .count() as u32
+    }
+    
+    #[test]
+    fn test_small_attackers_mostly_reach_legs_and_tails() {
+        let chicken = create_quadruped(1, 2_000);
+        let mut dragon = create_quadruped(2, 800_000);
+        
+        // Equal weights: a chicken lands well over three quarters of its hits low
+        let low = count_zone_hits(&chicken, &dragon, BodyZone::Low);
+        let high = count_zone_hits(&chicken, &dragon, BodyZone::High);
+        assert!(low > 750, "low hits: {}", low);
+        assert!(high < 50, "high hits: {}", high);
+        
+        // The dragon has no trouble reaching the chicken's head
+        assert!(count_zone_hits(&dragon, &chicken, BodyZone::High) > 250);
+        
+        // Pinned, the dragon's head is as exposed as anything else
+        dragon.pinned = true;
+        assert!(count_zone_hits(&chicken, &dragon, BodyZone::High) > 200);
+    }
+    
+    #[test]
+    fn test_armor_and_guard_shield_parts() {
+        let attacker = create_quadruped(1, 10_000);
+        let mut defender = create_quadruped(2, 10_000);
+        let torso_exposure = |d: &Actor| {
+            let torso = d.parts.iter().find(|p| p.part_id == "torso").unwrap();
+            CombatResolver::exposure(torso, &attacker, d)
+        };
+        
+        let bare = torso_exposure(&defender);
+        defender.parts.push(Part::attached("shell", &["armor"], &["torso"]));
+        assert_eq!(torso_exposure(&defender), bare / 2);
+        
+        defender.defending = true;
+        assert_eq!(torso_exposure(&defender), bare / 4);
+        
+        // The legs aren't shielded by either
+        let leg = defender.parts.iter().find(|p| p.part_id == "leg").unwrap();
+        assert_eq!(CombatResolver::exposure(leg, &attacker, &defender), bare);
+    }
+    
+    #[test]
+    fn test_quick_small_defenders_dodge_and_helpless_ones_cannot() {
+        let wolf = create_quadruped(1, 40_000);
+        let mut gecko = create_quadruped(2, 50);
+        gecko.speed = 9;
+        
+        // 18 from speed, 10 for slipping under a towering attacker
+        assert_eq!(CombatResolver::evasion(&wolf, &gecko), 28);
+        
+        // A mangled leg on its only leg halves the footwork
+        gecko.parts.iter_mut().find(|p| p.part_id == "leg").unwrap().hp = 1;
+        assert_eq!(CombatResolver::evasion(&wolf, &gecko), 19);
+        
+        gecko.stamina = 0;
+        assert_eq!(CombatResolver::evasion(&wolf, &gecko), 9);
+        
+        gecko.grappled_by = Some(1);
+        assert_eq!(CombatResolver::evasion(&wolf, &gecko), 0);
+    }
+    
+    #[test]
+    fn test_dodges_are_reported_apart_from_misses() {
+        let mut rng: SmallRng = Seeder::from(6u64).make_rng();
+        let mut attacker = create_test_actor(1);
+        let mut defender = create_test_actor(2);
+        defender.speed = 20;
+        defender.defending = true;
+        
+        let mut attack = create_test_attack();
+        attack.accuracy = 60;
+        
+        let (mut dodges, mut misses) = (0, 0);
+        for _ in 0..200 {
+            attacker.stamina = 100;
+            for event in CombatResolver::resolve_attack(&mut rng, &mut attacker, &mut defender, &attack, 0) {
+                match event {
+                    BattleEvent::Dodge { attacker_id: 1, defender_id: 2 } => dodges += 1,
+                    BattleEvent::StatusChange { status, .. } if status == "miss" => misses += 1,
+                    _ => {}
+                }
+            }
+            if !defender.is_alive() {
+                defender = create_test_actor(2);
+                defender.speed = 20;
+                defender.defending = true;
+            }
+        }
+        
+        // Evasion is capped at 40: roughly 80 misses and 80 dodges in 200 swings
+        assert!(dodges > 50 && misses > 50, "dodges {}, misses {}", dodges, misses);
+    }
+    
+    #[test]
+    fn test_ethereal_parts_fade_without_bleeding() {
+        let mut rng: SmallRng = Seeder::from(4u64).make_rng();
+        let mut void = Actor::new(1, "space_void".to_string(), 'V', "black".to_string(), 0, 0, 0);
+        let mut core = Part::attached("core", &["torso", "vital", "ethereal"], &[]);
+        core.base_bleed_rate = 3;
+        void.parts.push(core);
+        void.parts.push(Part::attached("tendril", &["grasp_weapon", "ethereal"], &["core"]));
+        
+        assert!(void.is_ethereal());
+        assert!(void.parts[0].organs().is_empty(), "Nothing inside to pierce");
+        assert!(CombatResolver::open_wound(&mut void, "core", 5).is_empty());
+        
+        let events = CombatResolver::destroy_part(&mut rng, &mut void, "tendril", true);
+        assert!(matches!(&events[..], [BattleEvent::Fade { part_id, .. }] if part_id == "tendril"));
+        assert_eq!(void.get_total_bleed_rate(), 0);
+    }
+    
+    #[test]
+    fn test_stone_cracks_and_shatters_without_bleeding_or_pain() {
+        let mut rng: SmallRng = Seeder::from(5u64).make_rng();
+        let mut golem = Actor::new(1, "rock_monster".to_string(), 'R', "gray".to_string(), 0, 0, 0);
+        let mut torso = Part::attached("torso", &["torso", "vital", "stone"], &[]);
+        torso.base_bleed_rate = 3;
+        golem.parts.push(torso);
+        let mut arm = Part::attached("arm", &["bash_weapon", "stone"], &["torso"]);
+        arm.armor = 16;
+        arm.hp = 40;
+        arm.max_hp = 40;
+        golem.parts.push(arm);
+        golem.parts.push(Part::attached("fist", &["stone"], &["arm"]));
+        
+        assert!(golem.is_stone() && golem.is_bloodless());
+        assert!(CombatResolver::open_wound(&mut golem, "torso", 5).is_empty());
+        assert!(PainResolver::inflict(&mut golem, 500).is_empty());
+        
+        // Cracking through the wound stages wears the armor down
+        CombatResolver::wound_part(&mut golem, "arm", 20);
+        assert_eq!(golem.parts[1].current_armor(), 12);
+        
+        let events = CombatResolver::destroy_part(&mut rng, &mut golem, "arm", false);
+        assert!(matches!(&events[0], BattleEvent::Shatter { part_id, gib_char: '*', debris: 13, .. } if part_id == "arm"));
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Sever { part_id, .. } if part_id == "fist")));
+        assert_eq!(golem.get_total_bleed_rate(), 0);
+    }
+    
+    #[test]
+    fn test_debris_hits_a_nearby_actor() {
+        let mut rng: SmallRng = Seeder::from(6u64).make_rng();
+        let mut wolf = create_test_actor(2);
+        let hp_before: i32 = wolf.parts.iter().map(|p| p.hp).sum();
+        
+        let events = CombatResolver::debris_hit(&mut rng, 1, &mut wolf, 13);
+        
+        assert!(matches!(&events[0], BattleEvent::Hit { attacker_id: 1, defender_id: 2, .. }));
+        assert!(wolf.parts.iter().map(|p| p.hp).sum::<i32>() < hp_before);
     }
 }
@@ -0,0 +1,73 @@
+use crate::anatomy::{Part, Tag};
+
+/// Percent of an attack's usual bleed chance that gets through fur
+pub const FUR_BLEED_PERCENT: u32 = 50;
+/// Hits that beat the defender's dodge by fewer points than this are
+/// glancing blows
+pub const GLANCING_MARGIN: u32 = 15;
+
+/// How body coverings change the blows that land on them. Feathers and
+/// scales work through the part's resistance table; fur and armor plates
+/// are resolved here.
+pub struct CoveringResolver;
+
+impl CoveringResolver {
+    /// Percent chance a hit on this part opens a bleeding wound. Fur mats
+    /// over shallow cuts.
+    pub fn bleed_chance(part: &Part, chance: u32) -> u32 {
+        if part.has_covering(Tag::Furred) {
+            chance * FUR_BLEED_PERCENT / 100
+        } else {
+            chance
+        }
+    }
+    
+    /// Whether a blow skids off the part's armor plates. `margin` is how far
+    /// the hit roll beat the defender's dodge; only glancing blows deflect,
+    /// and only off plates the attack can't punch straight through.
+    pub fn deflects(part: &Part, margin: u32, penetration: i32) -> bool {
+        margin < GLANCING_MARGIN && part.has_covering(Tag::Armored) && part.current_armor() > penetration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anatomy::part::Resistances;
+    use crate::sim::attack::DamageType;
+    
+    fn create_part(tags: &[&str], armor: i32) -> Part {
+        let mut part = Part::tagged("torso", tags);
+        part.resistances = Resistances::from_tags(&part.tags);
+        part.hp = 30;
+        part.max_hp = 30;
+        part.armor = armor;
+        part.base_bleed_rate = 3;
+        part
+    }
+    
+    #[test]
+    fn test_fur_halves_bleed_chance() {
+        assert_eq!(CoveringResolver::bleed_chance(&create_part(&["torso", "furred"], 0), 60), 30);
+        assert_eq!(CoveringResolver::bleed_chance(&create_part(&["torso", "feathered"], 0), 60), 60);
+    }
+    
+    #[test]
+    fn test_armor_plates_deflect_only_glancing_blows() {
+        let shell = create_part(&["armor"], 10);
+        assert!(shell.has_covering(Tag::Armored), "Plain \"armor\" counts as armor plates");
+        assert!(CoveringResolver::deflects(&shell, 0, 0));
+        assert!(!CoveringResolver::deflects(&shell, GLANCING_MARGIN, 0), "Solid hits land");
+        assert!(!CoveringResolver::deflects(&shell, 0, 10), "Piercing attacks punch through");
+        assert!(!CoveringResolver::deflects(&create_part(&["torso"], 10), 0, 0), "Bare hide has no plates");
+    }
+    
+    #[test]
+    fn test_feathers_cushion_blunt_and_scales_turn_blades() {
+        let feathered = create_part(&["feathered"], 0).resistances;
+        let scaled = create_part(&["scaled"], 0).resistances;
+        
+        assert!(feathered.against(DamageType::Blunt) < scaled.against(DamageType::Blunt));
+        assert!(scaled.against(DamageType::Slash) < feathered.against(DamageType::Slash));
+    }
+}
@@ -0,0 +1,230 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::sim::{Actor, Attack};
+use crate::sim::attack::DamageType;
+use crate::sim::pain::STUN_TICKS;
+use crate::anatomy::part::Part;
+use crate::events::BattleEvent;
+
+/// Percent chance any landed blow is critical
+pub const BASE_CRIT_CHANCE: u32 = 2;
+/// Extra crit chance while the attacker is berserk
+pub const BERSERK_CRIT_BONUS: u32 = 5;
+/// Extra crit chance for each ally pressing the defender from another side
+pub const FLANK_CRIT_BONUS: u32 = 3;
+/// Flanking allies beyond this many add nothing
+pub const MAX_FLANKERS: u32 = 2;
+/// Bleed rate opened by a blow to the heart
+pub const HEART_BLEED: u32 = 30;
+/// Bleed rate opened by a punctured lung
+pub const LUNG_BLEED: u32 = 12;
+
+/// What a critical hit did beyond ignoring armor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CriticalKind {
+    /// Slipped through a gap in the armor
+    ArmorGap,
+    /// Struck an internal organ
+    Organ,
+    /// Took the part clean off
+    Sever,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CriticalHit {
+    pub kind: CriticalKind,
+    /// The organ struck, for organ crits
+    pub organ: Option<String>,
+}
+
+pub struct CriticalResolver;
+
+impl CriticalResolver {
+    /// Percent chance a landed blow is critical. Sharper weapons find weak
+    /// spots more often (up to +5), as do berserkers and flanked defenders.
+    pub fn crit_chance(attacker: &Actor, attack: &Attack, flankers: u32) -> u32 {
+        let edged = attack.damage.share(DamageType::Pierce) + attack.damage.share(DamageType::Slash);
+        let mut chance = BASE_CRIT_CHANCE + edged / 20;
+        if attacker.is_berserk() {
+            chance += BERSERK_CRIT_BONUS;
+        }
+        chance + flankers.min(MAX_FLANKERS) * FLANK_CRIT_BONUS
+    }
+    
+    /// Roll for a critical against the struck part. Parts holding organs
+    /// take an organ strike, edged blows take limbs off (except stone ones,
+    /// which blunt the edge), and anything else finds a gap in the armor.
+    pub fn roll<R: Rng>(rng: &mut R, attacker: &Actor, attack: &Attack, part: &Part, flankers: u32) -> Option<CriticalHit> {
+        if rng.gen_range(0..100) >= Self::crit_chance(attacker, attack, flankers) {
+            return None;
+        }
+        
+        let organs = part.organs();
+        let hit = if !organs.is_empty() {
+            CriticalHit {
+                kind: CriticalKind::Organ,
+                organ: Some(organs[rng.gen_range(0..organs.len())].to_string()),
+            }
+        } else if attack.damage.can_sever() && !part.has_tag("vital") && !part.attachments.is_empty() && !part.is_stone() {
+            CriticalHit { kind: CriticalKind::Sever, organ: None }
+        } else {
+            CriticalHit { kind: CriticalKind::ArmorGap, organ: None }
+        };
+        Some(hit)
+    }
+    
+    /// Damage dealt by a critical: organ strikes hit half again as hard
+    pub fn damage(hit: &CriticalHit, damage: i32) -> i32 {
+        match hit.kind {
+            CriticalKind::Organ => damage * 3 / 2,
+            _ => damage,
+        }
+    }
+    
+    /// Line shown to players when the critical lands
+    pub fn flavor(hit: &CriticalHit, attack: &Attack, part: &Part) -> String {
+        let part_name = part.display_name.to_lowercase();
+        match (hit.kind, hit.organ.as_deref()) {
+            (CriticalKind::Organ, Some("heart")) => format!("{} pierces the heart!", attack.display_name),
+            (CriticalKind::Organ, Some("lung")) => format!("{} punctures a lung!", attack.display_name),
+            (CriticalKind::Organ, Some("brain")) => format!("{} cracks into the brain!", attack.display_name),
+            (CriticalKind::Organ, _) => format!("{} strikes deep into the {}!", attack.display_name, part_name),
+            (CriticalKind::Sever, _) => format!("{} takes the {} clean off!", attack.display_name, part_name),
+            (CriticalKind::ArmorGap, _) => format!("{} finds a gap in the {}'s guard!", attack.display_name, part_name),
+        }
+    }
+    
+    /// Aftermath of an organ strike: a struck brain stuns, a heart bleeds
+    /// heavily, and a punctured lung bleeds and leaves the actor winded
+    pub fn strike_organ(actor: &mut Actor, part_id: &str, organ: &str) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if !actor.is_alive() {
+            return events;
+        }
+        
+        let bleed = match organ {
+            "brain" => {
+                if !actor.is_incapacitated() {
+                    actor.stunned_ticks = STUN_TICKS;
+                    actor.energy = 0;
+                    events.push(BattleEvent::StatusChange {
+                        actor_id: actor.id,
+                        status: "stunned".to_string(),
+                        active: true,
+                    });
+                }
+                0
+            }
+            "heart" => HEART_BLEED,
+            "lung" => {
+                actor.stamina = 0;
+                LUNG_BLEED
+            }
+            _ => 0,
+        };
+        
+        if bleed > 0 {
+            if let Some(part) = actor.parts.iter_mut().find(|p| p.part_id == part_id) {
+                part.bleed_rate += bleed;
+                events.push(BattleEvent::Bleed {
+                    actor_id: actor.id,
+                    amount: bleed,
+                });
+            }
+        }
+        
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand_seeder::Seeder;
+    use crate::sim::Weapon;
+    use crate::sim::attack::AttackTemplate;
+    
+    fn create_part(part_id: &str, tags: &[&str], attachments: &[&str]) -> Part {
+        let mut part = Part::attached(part_id, tags, attachments);
+        part.hp = 20;
+        part.max_hp = 20;
+        part.base_bleed_rate = 2;
+        part
+    }
+    
+    fn create_attack(sharp: bool) -> Attack {
+        let table = AttackTemplate::load_test_table();
+        let tags: &[&str] = if sharp { &["bite_weapon", "sharp"] } else { &["bite_weapon"] };
+        let part = create_part("jaw", tags, &[]);
+        Attack::derive_from_tags(&table, &part.part_id, &part.display_name, &part.tags, part.hp, Weapon::new(1000, part.hp, 100)).remove(0)
+    }
+    
+    #[test]
+    fn test_crit_chance_rises_with_sharpness_fury_and_flanking() {
+        let mut attacker = Actor::new(1, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        let blunt = create_attack(false);
+        let sharp = create_attack(true);
+        
+        let base = CriticalResolver::crit_chance(&attacker, &blunt, 0);
+        assert!(CriticalResolver::crit_chance(&attacker, &sharp, 0) > base);
+        assert_eq!(CriticalResolver::crit_chance(&attacker, &blunt, 2), base + 2 * FLANK_CRIT_BONUS);
+        assert_eq!(CriticalResolver::crit_chance(&attacker, &blunt, 5), base + MAX_FLANKERS * FLANK_CRIT_BONUS);
+        
+        attacker.morale = 90;
+        attacker.hp = attacker.max_hp / 3;
+        assert_eq!(CriticalResolver::crit_chance(&attacker, &blunt, 0), base + BERSERK_CRIT_BONUS);
+    }
+    
+    #[test]
+    fn test_crits_strike_organs_or_take_limbs_off() {
+        let mut rng: SmallRng = Seeder::from(42u64).make_rng();
+        let mut attacker = Actor::new(1, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        attacker.morale = 90;
+        attacker.hp = attacker.max_hp / 3;
+        let sharp = create_attack(true);
+        let blunt = create_attack(false);
+        
+        let torso = create_part("torso", &["torso", "vital"], &[]);
+        let leg = create_part("leg", &["leg", "locomotion"], &["torso"]);
+        
+        let mut organs = Vec::new();
+        let mut kinds = Vec::new();
+        for _ in 0..500 {
+            if let Some(hit) = CriticalResolver::roll(&mut rng, &attacker, &sharp, &torso, 2) {
+                assert_eq!(hit.kind, CriticalKind::Organ);
+                organs.push(hit.organ.unwrap());
+            }
+            if let Some(hit) = CriticalResolver::roll(&mut rng, &attacker, &sharp, &leg, 2) {
+                kinds.push(hit.kind);
+            }
+            if let Some(hit) = CriticalResolver::roll(&mut rng, &attacker, &blunt, &leg, 2) {
+                assert_eq!(hit.kind, CriticalKind::ArmorGap, "Blunt blows can't sever");
+            }
+        }
+        
+        assert!(organs.iter().any(|o| o == "heart") && organs.iter().any(|o| o == "lung"));
+        assert!(!kinds.is_empty() && kinds.iter().all(|k| *k == CriticalKind::Sever));
+    }
+    
+    #[test]
+    fn test_organ_strikes_stun_bleed_and_wind() {
+        let mut actor = Actor::new(1, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        actor.parts.push(create_part("head", &["head", "brain", "vital"], &["torso"]));
+        actor.parts.push(create_part("torso", &["torso", "vital"], &[]));
+        actor.hp = 40;
+        actor.max_hp = 40;
+        actor.stamina = 50;
+        
+        let events = CriticalResolver::strike_organ(&mut actor, "head", "brain");
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::StatusChange { status, .. } if status == "stunned")));
+        assert_eq!(actor.stunned_ticks, STUN_TICKS);
+        
+        CriticalResolver::strike_organ(&mut actor, "torso", "heart");
+        CriticalResolver::strike_organ(&mut actor, "torso", "lung");
+        assert_eq!(actor.parts[1].bleed_rate, HEART_BLEED + LUNG_BLEED);
+        assert_eq!(actor.stamina, 0);
+    }
+}
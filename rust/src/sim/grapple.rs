@@ -0,0 +1,274 @@
+use rand::Rng;
+use crate::sim::{Actor, CombatResolver};
+use crate::sim::attack::AttackTemplate;
+use crate::sim::pain::PainResolver;
+use crate::events::BattleEvent;
+
+/// Stamina spent on each grapple maneuver (seize, pin, tear, throw)
+pub const GRAPPLE_STAMINA_COST: u32 = 10;
+/// Stamina spent on each attempt to break free
+pub const STRUGGLE_STAMINA_COST: u32 = 5;
+
+pub struct GrappleResolver;
+
+impl GrappleResolver {
+    /// Try to seize an adjacent, lighter enemy
+    pub fn seize<R: Rng>(
+        rng: &mut R,
+        table: &[AttackTemplate],
+        grappler: &mut Actor,
+        target: &mut Actor,
+    ) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if !grappler.can_grapple(table)
+            || grappler.grappling.is_some()
+            || target.grappled_by.is_some()
+            || target.mass_g >= grappler.mass_g
+            || grappler.airborne != target.airborne
+            || grappler.stamina < GRAPPLE_STAMINA_COST
+        {
+            return events;
+        }
+        
+        grappler.stamina -= GRAPPLE_STAMINA_COST;
+        
+        let chance = CombatResolver::bump_chance(grappler.mass_g, target.mass_g);
+        if rng.gen_range(0..100) >= chance {
+            events.push(BattleEvent::StatusChange {
+                actor_id: grappler.id,
+                status: "miss".to_string(),
+                active: true,
+            });
+            return events;
+        }
+        
+        grappler.grappling = Some(target.id);
+        target.grappled_by = Some(grappler.id);
+        target.reduce_morale(5);
+        
+        events.push(BattleEvent::Grapple {
+            grappler_id: grappler.id,
+            target_id: target.id,
+        });
+        
+        events
+    }
+    
+    /// Pin a held actor to the ground so it can neither move nor attack
+    pub fn pin<R: Rng>(rng: &mut R, grappler: &mut Actor, held: &mut Actor) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if grappler.grappling != Some(held.id) || held.pinned || grappler.stamina < GRAPPLE_STAMINA_COST {
+            return events;
+        }
+        
+        grappler.stamina -= GRAPPLE_STAMINA_COST;
+        
+        let chance = CombatResolver::bump_chance(grappler.mass_g, held.mass_g);
+        if rng.gen_range(0..100) < chance {
+            held.pinned = true;
+            held.reduce_morale(5);
+            events.push(BattleEvent::Pin {
+                grappler_id: grappler.id,
+                target_id: held.id,
+            });
+        }
+        
+        events
+    }
+    
+    /// Tear at a part of the held actor. The hold guarantees a hit, and a
+    /// part that's already badly hurt may be ripped clean off.
+    pub fn tear<R: Rng>(
+        rng: &mut R,
+        table: &[AttackTemplate],
+        grappler: &mut Actor,
+        held: &mut Actor,
+    ) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if grappler.grappling != Some(held.id) || held.parts.is_empty() || grappler.stamina < GRAPPLE_STAMINA_COST {
+            return events;
+        }
+        
+        grappler.stamina -= GRAPPLE_STAMINA_COST;
+        
+        // Tear with the grappler's strongest natural weapon
+        let weapon = match grappler
+            .get_available_attacks(table)
+            .into_iter()
+            .filter(|a| !a.is_hold())
+            .max_by_key(|a| a.damage.base_damage)
+        {
+            Some(attack) => attack,
+            None => return events,
+        };
+        
+        let target_part = CombatResolver::select_target_part(rng, grappler, held);
+        let part_id = target_part.part_id.clone();
+        let display_name = target_part.display_name.clone();
+        let (damage, _) = CombatResolver::resisted_damage(&weapon.damage, &target_part.resistances);
+        let damage = (damage - target_part.current_armor()).max(1);
+        let felt = if target_part.is_stone() { 0 } else { damage };
+        
+        if felt > 0 {
+            held.reduce_morale((felt as u32 / 3).max(1));
+        }
+        
+        events.push(BattleEvent::Hit {
+            attacker_id: grappler.id,
+            defender_id: held.id,
+            part_id: part_id.clone(),
+            damage: damage as u32,
+            attack_name: format!("Tear at {}", display_name),
+        });
+        
+        let (destroyed, wound_events) = CombatResolver::wound_part(held, &part_id, damage);
+        events.extend(wound_events);
+        let sever_chance = match held.parts.iter().find(|p| p.part_id == part_id) {
+            Some(part) => Self::tear_sever_chance(part.hp, part.max_hp),
+            None => return events,
+        };
+        
+        if destroyed || rng.gen_range(0..100) < sever_chance {
+            events.extend(CombatResolver::destroy_part(rng, held, &part_id, true));
+        } else {
+            events.extend(CombatResolver::open_wound(held, &part_id, damage));
+        }
+        
+        events.extend(PainResolver::inflict(held, felt));
+        
+        events
+    }
+    
+    /// Percent chance to rip off a damaged part: half its missing health
+    pub fn tear_sever_chance(hp: i32, max_hp: i32) -> u32 {
+        if max_hp <= 0 {
+            return 0;
+        }
+        let missing = (max_hp - hp.max(0)).max(0);
+        (missing * 50 / max_hp) as u32
+    }
+    
+    /// Held actor tries to wrench itself free. Heavier actors break holds
+    /// more easily; being pinned halves the odds.
+    pub fn struggle<R: Rng>(rng: &mut R, held: &mut Actor, grappler: &mut Actor) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if held.grappled_by != Some(grappler.id) || held.stamina < STRUGGLE_STAMINA_COST {
+            return events;
+        }
+        
+        held.stamina -= STRUGGLE_STAMINA_COST;
+        
+        let mut chance = CombatResolver::bump_chance(held.mass_g, grappler.mass_g);
+        if held.pinned {
+            chance /= 2;
+        }
+        
+        if rng.gen_range(0..100) < chance {
+            Self::release(grappler, held);
+            events.push(BattleEvent::BreakFree {
+                actor_id: held.id,
+                grappler_id: grappler.id,
+            });
+        }
+        
+        events
+    }
+    
+    /// End a hold on both sides
+    pub fn release(grappler: &mut Actor, held: &mut Actor) {
+        if grappler.grappling == Some(held.id) {
+            grappler.grappling = None;
+        }
+        if held.grappled_by == Some(grappler.id) {
+            held.grappled_by = None;
+            held.pinned = false;
+        }
+    }
+    
+    /// Cells a thrown actor flies, from the thrower's mass advantage
+    pub fn throw_distance(thrower_mass: u32, held_mass: u32) -> i32 {
+        if CombatResolver::outweighs(thrower_mass, held_mass, 8) {
+            3
+        } else if CombatResolver::outweighs(thrower_mass, held_mass, 3) {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand_seeder::Seeder;
+    use crate::anatomy::part::Part;
+    
+    fn create_grappler(id: u32, mass_g: u32) -> Actor {
+        let mut actor = Actor::new(id, "test".to_string(), 'G', "white".to_string(), 0, 0, 0);
+        actor.mass_g = mass_g;
+        let mut hand = Part::tagged("hand", &["grasp", "scratch_weapon"]);
+        hand.hp = 20;
+        hand.max_hp = 20;
+        actor.parts.push(hand);
+        let mut torso = Part::tagged("torso", &["torso", "vital"]);
+        torso.hp = 40;
+        torso.max_hp = 40;
+        actor.parts.push(torso);
+        actor
+    }
+    
+    #[test]
+    fn test_only_lighter_enemies_can_be_seized() {
+        let mut rng: SmallRng = Seeder::from(1u64).make_rng();
+        
+        let mut small = create_grappler(1, 5_000);
+        let mut big = create_grappler(2, 300_000);
+        assert!(GrappleResolver::seize(&mut rng, &[], &mut small, &mut big).is_empty());
+        assert!(big.grappled_by.is_none());
+        
+        // A 60x heavier grappler lands the hold within a few tries
+        for _ in 0..10 {
+            big.stamina = 100;
+            GrappleResolver::seize(&mut rng, &[], &mut big, &mut small);
+        }
+        assert_eq!(big.grappling, Some(1));
+        assert_eq!(small.grappled_by, Some(2));
+    }
+    
+    #[test]
+    fn test_struggle_breaks_free_and_clears_pin() {
+        let mut rng: SmallRng = Seeder::from(2u64).make_rng();
+        let mut grappler = create_grappler(1, 50_000);
+        let mut held = create_grappler(2, 45_000);
+        grappler.grappling = Some(2);
+        held.grappled_by = Some(1);
+        held.pinned = true;
+        
+        let mut freed = false;
+        for _ in 0..50 {
+            held.stamina = 100;
+            let events = GrappleResolver::struggle(&mut rng, &mut held, &mut grappler);
+            if events.iter().any(|e| matches!(e, BattleEvent::BreakFree { .. })) {
+                freed = true;
+                break;
+            }
+        }
+        
+        assert!(freed);
+        assert!(grappler.grappling.is_none());
+        assert!(held.grappled_by.is_none());
+        assert!(!held.pinned);
+    }
+    
+    #[test]
+    fn test_tear_sever_chance_grows_with_damage() {
+        assert_eq!(GrappleResolver::tear_sever_chance(20, 20), 0);
+        assert_eq!(GrappleResolver::tear_sever_chance(10, 20), 25);
+        assert_eq!(GrappleResolver::tear_sever_chance(0, 20), 50);
+    }
+}
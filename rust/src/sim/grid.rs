@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -5,6 +6,8 @@ pub struct Grid {
     width: i32,
     height: i32,
     cells: Vec<Cell>,
+    /// Persistent things lying on the ground: corpses, gibs and decals
+    ground: Ground,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +25,74 @@ pub enum TileType {
     Stone,
 }
 
+/// Something left lying on the arena floor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum GroundEntity {
+    Corpse {
+        actor_id: u32,
+        species_id: String,
+        glyph: char,
+        x: i32,
+        y: i32,
+    },
+    Gib {
+        actor_id: u32,
+        part_id: String,
+        glyph: char,
+        x: i32,
+        y: i32,
+    },
+    /// Stain that accumulates on a tile as more fluid lands on it
+    Decal {
+        decal: DecalKind,
+        amount: u32,
+        x: i32,
+        y: i32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DecalKind {
+    Blood,
+    Vomit,
+}
+
+/// The ground layer. Decals are totalled per tile and kind so splashes
+/// add up without a scan; everything serializes as one list of entities.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<GroundEntity>", into = "Vec<GroundEntity>")]
+struct Ground {
+    remains: Vec<GroundEntity>,
+    decals: BTreeMap<(i32, i32, DecalKind), u32>,
+}
+
+impl From<Vec<GroundEntity>> for Ground {
+    fn from(entities: Vec<GroundEntity>) -> Self {
+        let mut ground = Ground::default();
+        for entity in entities {
+            match entity {
+                GroundEntity::Decal { decal, amount, x, y } => {
+                    let total = ground.decals.entry((x, y, decal)).or_insert(0);
+                    *total = total.saturating_add(amount);
+                }
+                other => ground.remains.push(other),
+            }
+        }
+        ground
+    }
+}
+
+impl From<Ground> for Vec<GroundEntity> {
+    fn from(ground: Ground) -> Self {
+        let decals = ground.decals
+            .into_iter()
+            .map(|((x, y, decal), amount)| GroundEntity::Decal { decal, amount, x, y });
+        ground.remains.into_iter().chain(decals).collect()
+    }
+}
+
 impl Grid {
     pub fn new(width: i32, height: i32) -> Self {
         let size = (width * height) as usize;
@@ -34,6 +105,7 @@ impl Grid {
             width,
             height,
             cells,
+            ground: Ground::default(),
         }
     }
     
@@ -56,4 +128,65 @@ impl Grid {
     pub fn is_walkable(&self, x: i32, y: i32) -> bool {
         self.get_cell(x, y).map(|c| c.walkable).unwrap_or(false)
     }
+    
+    /// Airborne actors can pass over any cell in the arena, including
+    /// obstacles and water
+    pub fn is_flyable(&self, x: i32, y: i32) -> bool {
+        self.get_cell(x, y).is_some()
+    }
+    
+    /// Clamp a position into the arena bounds
+    pub fn clamp_position(&self, x: i32, y: i32) -> (i32, i32) {
+        (x.clamp(0, self.width - 1), y.clamp(0, self.height - 1))
+    }
+    
+    /// Leave a corpse where an actor died
+    pub fn add_corpse(&mut self, actor_id: u32, species_id: String, glyph: char, x: i32, y: i32) {
+        self.ground.remains.push(GroundEntity::Corpse { actor_id, species_id, glyph, x, y });
+    }
+    
+    /// Drop a severed part on the floor
+    pub fn add_gib(&mut self, actor_id: u32, part_id: String, glyph: char, x: i32, y: i32) {
+        self.ground.remains.push(GroundEntity::Gib { actor_id, part_id, glyph, x, y });
+    }
+    
+    /// Splash fluid on a tile, adding to any existing decal of the same kind
+    pub fn add_decal(&mut self, decal: DecalKind, x: i32, y: i32, amount: u32) {
+        if self.get_cell(x, y).is_none() || amount == 0 {
+            return;
+        }
+        
+        let total = self.ground.decals.entry((x, y, decal)).or_insert(0);
+        *total = total.saturating_add(amount);
+    }
+    
+    /// Total amount of a decal kind on a tile
+    pub fn decal_amount(&self, decal: DecalKind, x: i32, y: i32) -> u32 {
+        self.ground.decals.get(&(x, y, decal)).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_decals_accumulate_per_tile() {
+        let mut grid = Grid::new(10, 10);
+        
+        grid.add_decal(DecalKind::Blood, 2, 3, 2);
+        grid.add_decal(DecalKind::Blood, 2, 3, 3);
+        grid.add_decal(DecalKind::Vomit, 2, 3, 1);
+        grid.add_decal(DecalKind::Blood, 4, 4, 1);
+        grid.add_decal(DecalKind::Blood, 40, 4, 1); // out of bounds
+        
+        assert_eq!(grid.decal_amount(DecalKind::Blood, 2, 3), 5);
+        assert_eq!(grid.decal_amount(DecalKind::Vomit, 2, 3), 1);
+        
+        // Each tile and kind is one decal entity in the serialized ground
+        let state = serde_json::to_value(&grid).unwrap();
+        let ground = state["ground"].as_array().unwrap();
+        assert_eq!(ground.len(), 3);
+        assert!(ground.iter().any(|e| e["kind"] == "decal" && e["decal"] == "blood" && e["amount"] == 5));
+    }
 }
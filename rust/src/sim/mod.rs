@@ -5,6 +5,15 @@ pub mod attack;
 pub mod action;
 pub mod combat;
 pub mod ai;
+pub mod spawn;
+pub mod arena;
+pub mod grapple;
+pub mod pain;
+pub mod critical;
+pub mod venom;
+pub mod nausea;
+pub mod aura;
+pub mod covering;
 
 #[cfg(test)]
 mod tests;
@@ -12,7 +21,7 @@ mod tests;
 pub use battle::Battle;
 pub use actor::Actor;
 pub use grid::Grid;
-pub use attack::{Attack, AttackType, DamageProfile};
+pub use attack::{Attack, AttackOverride, AttackType, DamageProfile, Weapon};
 pub use action::{Action, CombatAction};
 pub use combat::CombatResolver;
 pub use ai::SimpleAI;
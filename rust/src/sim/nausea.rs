@@ -0,0 +1,130 @@
+use crate::sim::Actor;
+use crate::sim::pain::STAGGER_PAIN;
+use crate::anatomy::part::WoundLevel;
+use crate::events::BattleEvent;
+
+/// Nausea at which the actor doubles over and vomits
+pub const VOMIT_NAUSEA: u32 = 100;
+/// Nausea that settles every tick
+pub const NAUSEA_DECAY: u32 = 4;
+/// Ceiling on the chance to slip in a puddle of vomit
+pub const MAX_SLIP_CHANCE: u32 = 40;
+
+pub struct NauseaResolver;
+
+impl NauseaResolver {
+    /// Nausea an actor's condition adds this tick: gut wounds, pain past the
+    /// stagger threshold, venom and exhaustion all turn the stomach.
+    /// Bloodless stone and ethereal actors have no stomach to turn.
+    pub fn nausea_gain(actor: &Actor) -> u32 {
+        if actor.is_bloodless() {
+            return 0;
+        }
+        
+        let gut: u32 = actor
+            .parts
+            .iter()
+            .filter(|p| p.has_tag("torso"))
+            .map(|p| match p.wound_level() {
+                WoundLevel::Wounded => 3,
+                WoundLevel::Mangled => 6,
+                _ => 0,
+            })
+            .sum();
+        let pain = actor.pain.saturating_sub(STAGGER_PAIN) / 5;
+        let toxin = actor.toxin / 5;
+        let fatigue = if actor.stamina < actor.max_stamina / 4 { 2 } else { 0 };
+        
+        gut + pain + toxin + fatigue
+    }
+    
+    /// Let nausea build or settle for a tick. An actor that passes the
+    /// threshold vomits, losing its banked turn.
+    pub fn churn(actor: &mut Actor) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if !actor.is_alive() {
+            return events;
+        }
+        
+        actor.nausea = (actor.nausea + Self::nausea_gain(actor)).saturating_sub(NAUSEA_DECAY);
+        if actor.nausea >= VOMIT_NAUSEA {
+            actor.nausea = 0;
+            actor.energy = 0;
+            events.push(BattleEvent::Vomit {
+                actor_id: actor.id,
+                amount: Self::vomit_amount(actor.mass_g),
+                x: actor.x,
+                y: actor.y,
+            });
+        }
+        
+        events
+    }
+    
+    /// Size of the puddle, from one splash for anything up to 10 kg to ten
+    /// for the largest beasts
+    pub fn vomit_amount(mass_g: u32) -> u32 {
+        (mass_g / 10_000).clamp(1, 10)
+    }
+    
+    /// Percent chance to slip stepping onto a tile with this much vomit
+    pub fn slip_chance(vomit: u32) -> u32 {
+        (vomit * 10).min(MAX_SLIP_CHANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anatomy::part::Part;
+    
+    fn create_actor() -> Actor {
+        let mut actor = Actor::new(1, "test".to_string(), 'T', "white".to_string(), 0, 3, 4);
+        let mut torso = Part::tagged("torso", &["torso", "vital"]);
+        torso.hp = 40;
+        torso.max_hp = 40;
+        actor.parts.push(torso);
+        actor
+    }
+    
+    #[test]
+    fn test_gut_wounds_and_pain_build_to_vomiting() {
+        let mut actor = create_actor();
+        actor.parts[0].hp = 10;
+        actor.pain = 75;
+        actor.energy = 20;
+        
+        let mut vomit = Vec::new();
+        for _ in 0..20 {
+            vomit.extend(NauseaResolver::churn(&mut actor));
+            if !vomit.is_empty() {
+                break;
+            }
+        }
+        
+        assert!(matches!(vomit[..], [BattleEvent::Vomit { actor_id: 1, amount: 1, x: 3, y: 4 }]));
+        assert_eq!(actor.energy, 0, "Vomiting costs the banked turn");
+        assert_eq!(actor.nausea, 0);
+    }
+    
+    #[test]
+    fn test_nausea_settles_once_the_cause_is_gone() {
+        let mut actor = create_actor();
+        actor.nausea = 50;
+        assert_eq!(NauseaResolver::nausea_gain(&actor), 0);
+        
+        for _ in 0..20 {
+            assert!(NauseaResolver::churn(&mut actor).is_empty());
+        }
+        assert_eq!(actor.nausea, 0);
+    }
+    
+    #[test]
+    fn test_bigger_puddles_are_more_slippery() {
+        assert_eq!(NauseaResolver::slip_chance(0), 0);
+        assert!(NauseaResolver::slip_chance(1) < NauseaResolver::slip_chance(3));
+        assert_eq!(NauseaResolver::slip_chance(50), MAX_SLIP_CHANCE);
+        assert_eq!(NauseaResolver::vomit_amount(500_000), 10);
+    }
+}
@@ -0,0 +1,176 @@
+use crate::sim::{Actor, CombatResolver};
+use crate::events::BattleEvent;
+
+/// Pain at which a hit knocks the actor off balance, costing its banked turn
+pub const STAGGER_PAIN: u32 = 25;
+/// Pain at which the actor is stunned for several ticks
+pub const STUN_PAIN: u32 = 50;
+/// Pain at which the actor collapses into shock
+pub const SHOCK_PAIN: u32 = 90;
+/// Shocked actors come round once pain fades below this
+pub const SHOCK_RECOVERY_PAIN: u32 = 40;
+/// Ceiling on accumulated pain
+pub const MAX_PAIN: u32 = 150;
+/// Pain that fades every tick
+pub const PAIN_DECAY: u32 = 3;
+/// Ticks a stun lasts
+pub const STUN_TICKS: u32 = 3;
+
+pub struct PainResolver;
+
+impl PainResolver {
+    /// Pain caused by a wound, relative to the actor's size and tolerance.
+    /// Losing a tenth of max HP hurts 20 at tolerance 50; tolerance 100 and
+    /// stone bodies feel nothing.
+    pub fn pain_from_damage(actor: &Actor, damage: i32) -> u32 {
+        if damage <= 0 || actor.is_stone() {
+            return 0;
+        }
+        let sensitivity = 100 - actor.pain_tolerance.min(100) as i64;
+        (damage as i64 * 4 * sensitivity / actor.max_hp.max(1) as i64) as u32
+    }
+    
+    /// Add pain from a wound and react to any threshold it crosses. Only the
+    /// worst reaction applies: shock, then stun, then stagger.
+    pub fn inflict(actor: &mut Actor, damage: i32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if !actor.is_alive() {
+            return events;
+        }
+        
+        let before = actor.pain;
+        actor.pain = (actor.pain + Self::pain_from_damage(actor, damage)).min(MAX_PAIN);
+        let crossed = |threshold: u32| before < threshold && actor.pain >= threshold;
+        
+        if crossed(SHOCK_PAIN) && !actor.in_shock {
+            actor.in_shock = true;
+            actor.energy = 0;
+            events.push(BattleEvent::StatusChange {
+                actor_id: actor.id,
+                status: "shock".to_string(),
+                active: true,
+            });
+            
+            events.extend(CombatResolver::fall_from_sky(actor));
+        } else if crossed(STUN_PAIN) && !actor.is_incapacitated() {
+            actor.stunned_ticks = STUN_TICKS;
+            actor.energy = 0;
+            events.push(BattleEvent::StatusChange {
+                actor_id: actor.id,
+                status: "stunned".to_string(),
+                active: true,
+            });
+        } else if crossed(STAGGER_PAIN) && !actor.is_incapacitated() {
+            actor.energy = 0;
+            events.push(BattleEvent::StatusChange {
+                actor_id: actor.id,
+                status: "staggered".to_string(),
+                active: true,
+            });
+        }
+        
+        events
+    }
+    
+    /// Let pain fade for a tick, wearing off stuns and bringing shocked
+    /// actors round once the pain is bearable again
+    pub fn recover(actor: &mut Actor) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        actor.pain = actor.pain.saturating_sub(PAIN_DECAY);
+        
+        if actor.stunned_ticks > 0 {
+            actor.stunned_ticks -= 1;
+            if actor.stunned_ticks == 0 {
+                events.push(BattleEvent::StatusChange {
+                    actor_id: actor.id,
+                    status: "stunned".to_string(),
+                    active: false,
+                });
+            }
+        }
+        
+        if actor.in_shock && actor.pain < SHOCK_RECOVERY_PAIN {
+            actor.in_shock = false;
+            events.push(BattleEvent::StatusChange {
+                actor_id: actor.id,
+                status: "shock".to_string(),
+                active: false,
+            });
+        }
+        
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn create_actor(pain_tolerance: u32, max_hp: i32) -> Actor {
+        let mut actor = Actor::new(1, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        actor.pain_tolerance = pain_tolerance;
+        actor.max_hp = max_hp;
+        actor.hp = max_hp;
+        actor
+    }
+    
+    #[test]
+    fn test_tolerant_actors_shrug_off_hits_that_drop_fragile_ones() {
+        let mut dragon = create_actor(95, 400);
+        let mut gerbil = create_actor(25, 20);
+        
+        let dragon_events = PainResolver::inflict(&mut dragon, 10);
+        let gerbil_events = PainResolver::inflict(&mut gerbil, 10);
+        
+        assert!(dragon_events.is_empty());
+        assert!(!dragon.is_incapacitated());
+        
+        assert!(gerbil.in_shock, "Half its body in one bite should send a gerbil into shock");
+        assert!(gerbil_events.iter().any(|e| matches!(e, BattleEvent::StatusChange { status, active: true, .. } if status == "shock")));
+    }
+    
+    #[test]
+    fn test_thresholds_escalate_from_stagger_to_stun() {
+        let mut actor = create_actor(50, 100);
+        actor.energy = 20;
+        
+        // 15 damage = 30 pain: staggered, banked energy lost
+        let events = PainResolver::inflict(&mut actor, 15);
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::StatusChange { status, .. } if status == "staggered")));
+        assert_eq!(actor.energy, 0);
+        assert!(!actor.is_incapacitated());
+        
+        // Another 15 crosses the stun threshold
+        let events = PainResolver::inflict(&mut actor, 15);
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::StatusChange { status, .. } if status == "stunned")));
+        assert_eq!(actor.stunned_ticks, STUN_TICKS);
+        
+        for _ in 0..STUN_TICKS {
+            assert!(actor.is_incapacitated());
+            PainResolver::recover(&mut actor);
+        }
+        assert!(!actor.is_incapacitated());
+        assert_eq!(actor.pain, 60 - PAIN_DECAY * STUN_TICKS);
+    }
+    
+    #[test]
+    fn test_shock_lasts_until_pain_fades() {
+        let mut actor = create_actor(0, 100);
+        actor.airborne = true;
+        
+        let events = PainResolver::inflict(&mut actor, 50);
+        assert!(actor.in_shock);
+        assert!(!actor.airborne, "Shocked flyers fall");
+        assert!(events.iter().any(|e| matches!(e, BattleEvent::Grounded { .. })));
+        
+        let mut ticks = 0;
+        while actor.in_shock {
+            PainResolver::recover(&mut actor);
+            ticks += 1;
+        }
+        assert!(actor.pain < SHOCK_RECOVERY_PAIN);
+        assert!(ticks > 1);
+    }
+}
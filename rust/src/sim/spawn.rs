@@ -0,0 +1,319 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::grid::Grid;
+
+/// How a group of combatants is arranged when it enters the arena
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Formation {
+    /// Ranks of columns facing the enemy
+    #[default]
+    Line,
+    /// Arrowhead with a leader at the front
+    Wedge,
+    /// Tight blob around the middle of the spawn zone
+    Cluster,
+    /// Random cells across the team's half of the arena
+    Scattered,
+    /// Spread evenly around the arena border, encircling the enemy
+    Surround,
+}
+
+/// Which edge of the arena a group spawns or arrives from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpawnSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl SpawnSide {
+    /// Whether the edge runs along the top or bottom of the arena
+    fn is_horizontal(&self) -> bool {
+        matches!(self, SpawnSide::Top | SpawnSide::Bottom)
+    }
+}
+
+/// Assigns spawn cells so that every combatant gets its own walkable cell
+pub struct SpawnPlanner<'a> {
+    grid: &'a Grid,
+    depth: i32,
+    occupied: HashSet<(i32, i32)>,
+}
+
+impl<'a> SpawnPlanner<'a> {
+    /// Create a planner whose spawn zones reach `depth` columns in from each edge
+    pub fn new(grid: &'a Grid, depth: i32) -> Self {
+        Self {
+            grid,
+            depth: depth.max(1),
+            occupied: HashSet::new(),
+        }
+    }
+    
+    /// Mark a cell as unavailable, e.g. one already held by a combatant
+    pub fn block(&mut self, x: i32, y: i32) {
+        self.occupied.insert((x, y));
+    }
+    
+    /// Claim an explicitly requested cell
+    pub fn reserve(&mut self, x: i32, y: i32) -> Result<(), String> {
+        if !self.grid.is_walkable(x, y) {
+            return Err(format!("Spawn position ({}, {}) is not walkable", x, y));
+        }
+        if !self.occupied.insert((x, y)) {
+            return Err(format!("Spawn position ({}, {}) is already taken", x, y));
+        }
+        Ok(())
+    }
+    
+    /// Place `count` combatants in a formation, returning one unique cell each.
+    /// Either every combatant gets a cell or none are claimed.
+    pub fn plan<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        formation: Formation,
+        side: SpawnSide,
+        count: usize,
+    ) -> Result<Vec<(i32, i32)>, String> {
+        let ideals = self.ideal_positions(rng, formation, side, count);
+        
+        let mut cells = Vec::with_capacity(count);
+        for (x, y) in ideals {
+            let cell = match self.nearest_free(x, y) {
+                Some(cell) => cell,
+                None => {
+                    for cell in &cells {
+                        self.occupied.remove(cell);
+                    }
+                    return Err("Arena is too small to spawn every combatant".to_string());
+                }
+            };
+            self.occupied.insert(cell);
+            cells.push(cell);
+        }
+        
+        Ok(cells)
+    }
+    
+    /// Preferred (possibly overlapping) positions for a formation before
+    /// they are snapped to free cells
+    fn ideal_positions<R: Rng>(
+        &self,
+        rng: &mut R,
+        formation: Formation,
+        side: SpawnSide,
+        count: usize,
+    ) -> Vec<(i32, i32)> {
+        let width = self.grid.width();
+        let height = self.grid.height();
+        // Formations are laid out as (depth in from the edge, position along the edge)
+        let edge_len = if side.is_horizontal() { width } else { height };
+        let center = edge_len / 2;
+        let rows = (edge_len - 2).max(1);
+        
+        match formation {
+            Formation::Line => {
+                (0..count as i32)
+                    .map(|i| {
+                        let column = i / rows;
+                        let in_column = (count as i32 - column * rows).min(rows);
+                        let spacing = if in_column * 2 <= rows { 2 } else { 1 };
+                        let row = i % rows;
+                        let along = center - (in_column - 1) * spacing / 2 + row * spacing;
+                        self.edge_cell(side, self.depth - column, along)
+                    })
+                    .collect()
+            }
+            Formation::Wedge => {
+                (0..count as i32)
+                    .map(|i| {
+                        let rank = (i + 1) / 2;
+                        let flank = if i % 2 == 1 { -1 } else { 1 };
+                        self.edge_cell(side, self.depth - rank, center + flank * rank)
+                    })
+                    .collect()
+            }
+            Formation::Cluster => {
+                let cell = self.edge_cell(side, self.depth - self.depth / 2, center);
+                vec![cell; count]
+            }
+            Formation::Scattered => {
+                let ((min_x, max_x), (min_y, max_y)) = match side {
+                    SpawnSide::Left => ((1, (width / 2 - 1).max(1)), (1, (height - 2).max(1))),
+                    SpawnSide::Right => (((width / 2 + 1).min(width - 2), width - 2), (1, (height - 2).max(1))),
+                    SpawnSide::Top => ((1, (width - 2).max(1)), (1, (height / 2 - 1).max(1))),
+                    SpawnSide::Bottom => ((1, (width - 2).max(1)), ((height / 2 + 1).min(height - 2), height - 2)),
+                };
+                (0..count)
+                    .map(|_| {
+                        let x = rng.gen_range(min_x..=max_x.max(min_x));
+                        let y = rng.gen_range(min_y..=max_y.max(min_y));
+                        (x, y)
+                    })
+                    .collect()
+            }
+            Formation::Surround => {
+                let perimeter = self.border_cells(side);
+                if perimeter.is_empty() {
+                    return vec![self.edge_cell(side, self.depth, center); count];
+                }
+                (0..count)
+                    .map(|i| perimeter[i * perimeter.len() / count.max(1)])
+                    .collect()
+            }
+        }
+    }
+    
+    /// Convert a (depth in from the edge, position along the edge) pair to a grid cell
+    fn edge_cell(&self, side: SpawnSide, depth: i32, along: i32) -> (i32, i32) {
+        match side {
+            SpawnSide::Left => (depth, along),
+            SpawnSide::Right => (self.grid.width() - 1 - depth, along),
+            SpawnSide::Top => (along, depth),
+            SpawnSide::Bottom => (along, self.grid.height() - 1 - depth),
+        }
+    }
+    
+    /// Cells one step in from the arena edge, walked around the border
+    /// starting from the middle of the team's own side
+    fn border_cells(&self, side: SpawnSide) -> Vec<(i32, i32)> {
+        let (min_x, max_x) = (1, self.grid.width() - 2);
+        let (min_y, max_y) = (1, self.grid.height() - 2);
+        if max_x < min_x || max_y < min_y {
+            return Vec::new();
+        }
+        
+        // Clockwise loop starting at the top-left corner
+        let mut ring = Vec::new();
+        for x in min_x..=max_x {
+            ring.push((x, min_y));
+        }
+        for y in (min_y + 1)..=max_y {
+            ring.push((max_x, y));
+        }
+        if max_y > min_y {
+            for x in (min_x..max_x).rev() {
+                ring.push((x, max_y));
+            }
+        }
+        if max_x > min_x {
+            for y in ((min_y + 1)..max_y).rev() {
+                ring.push((min_x, y));
+            }
+        }
+        
+        // Rotate so the walk begins on our own side
+        let start_cell = match side {
+            SpawnSide::Left => (min_x, (min_y + max_y) / 2),
+            SpawnSide::Right => (max_x, (min_y + max_y) / 2),
+            SpawnSide::Top => ((min_x + max_x) / 2, min_y),
+            SpawnSide::Bottom => ((min_x + max_x) / 2, max_y),
+        };
+        let start = ring.iter().position(|&c| c == start_cell).unwrap_or(0);
+        ring.rotate_left(start);
+        ring
+    }
+    
+    /// Closest walkable, unclaimed cell to a point, searching outward in rings
+    fn nearest_free(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        let x = x.clamp(0, self.grid.width() - 1);
+        let y = y.clamp(0, self.grid.height() - 1);
+        let is_free = |cx: i32, cy: i32| {
+            self.grid.is_walkable(cx, cy) && !self.occupied.contains(&(cx, cy))
+        };
+        
+        if is_free(x, y) {
+            return Some((x, y));
+        }
+        
+        let max_radius = self.grid.width().max(self.grid.height());
+        for radius in 1..=max_radius {
+            for dy in -radius..=radius {
+                for dx in -radius..=radius {
+                    if dx.abs() != radius && dy.abs() != radius {
+                        continue;
+                    }
+                    if is_free(x + dx, y + dy) {
+                        return Some((x + dx, y + dy));
+                    }
+                }
+            }
+        }
+        
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand_seeder::Seeder;
+    
+    const ALL_FORMATIONS: [Formation; 5] = [
+        Formation::Line,
+        Formation::Wedge,
+        Formation::Cluster,
+        Formation::Scattered,
+        Formation::Surround,
+    ];
+    
+    #[test]
+    fn test_formations_never_share_cells() {
+        let grid = Grid::new(24, 16);
+        
+        for formation in ALL_FORMATIONS {
+            let mut rng: SmallRng = Seeder::from(12345u64).make_rng();
+            let mut planner = SpawnPlanner::new(&grid, 3);
+            
+            let mut cells = planner.plan(&mut rng, formation, SpawnSide::Left, 40).unwrap();
+            cells.extend(planner.plan(&mut rng, formation, SpawnSide::Right, 40).unwrap());
+            
+            let unique: HashSet<(i32, i32)> = cells.iter().copied().collect();
+            assert_eq!(unique.len(), 80, "{:?} produced overlapping spawns", formation);
+            assert!(cells.iter().all(|&(x, y)| grid.is_walkable(x, y)));
+        }
+    }
+    
+    #[test]
+    fn test_top_and_bottom_edges_spawn_along_their_rows() {
+        let grid = Grid::new(30, 20);
+        let mut rng: SmallRng = Seeder::from(3u64).make_rng();
+        let mut planner = SpawnPlanner::new(&grid, 3);
+        
+        let top = planner.plan(&mut rng, Formation::Line, SpawnSide::Top, 10).unwrap();
+        let bottom = planner.plan(&mut rng, Formation::Line, SpawnSide::Bottom, 10).unwrap();
+        
+        assert!(top.iter().all(|&(_, y)| y <= 3));
+        assert!(bottom.iter().all(|&(_, y)| y >= 20 - 1 - 3));
+    }
+    
+    #[test]
+    fn test_reserved_cells_are_skipped() {
+        let grid = Grid::new(20, 12);
+        let mut rng: SmallRng = Seeder::from(1u64).make_rng();
+        let mut planner = SpawnPlanner::new(&grid, 3);
+        
+        planner.reserve(5, 5).unwrap();
+        assert!(planner.reserve(5, 5).is_err(), "Duplicate explicit positions must fail");
+        assert!(planner.reserve(-1, 5).is_err(), "Out-of-bounds positions must fail");
+        
+        let cells = planner.plan(&mut rng, Formation::Cluster, SpawnSide::Left, 10).unwrap();
+        assert!(!cells.contains(&(5, 5)));
+    }
+    
+    #[test]
+    fn test_overfull_arena_is_an_error() {
+        let grid = Grid::new(3, 3);
+        let mut rng: SmallRng = Seeder::from(1u64).make_rng();
+        let mut planner = SpawnPlanner::new(&grid, 1);
+        
+        assert!(planner.plan(&mut rng, Formation::Line, SpawnSide::Left, 10).is_err());
+        assert!(planner.occupied.is_empty(), "A failed plan must release the cells it claimed");
+    }
+}
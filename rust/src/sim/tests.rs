@@ -57,4 +57,451 @@ mod integration_tests {
             tick_count, 
             if battle.get_winner() == 0 { "A (Chickens)" } else { "B (Baboon)" });
     }
+    
+    #[test]
+    fn test_faster_actors_act_more_often() {
+        let mut battle = Battle::new(7);
+        
+        // Mouse (speed 8) vs turtle (speed 2): both start far apart, so the
+        // opening ticks are pure movement
+        let team_a_json = r#"[{"species_id": "mouse", "variation": {"speed_multiplier": 1.0}}]"#;
+        let team_b_json = r#"[{"species_id": "turtle", "variation": {"speed_multiplier": 1.0}}]"#;
+        
+        let result = battle.init_with_species("../data/species", team_a_json, team_b_json);
+        assert!(result.is_ok(), "Failed to initialize battle: {:?}", result);
+        
+        let mut mouse_moves = 0;
+        let mut turtle_moves = 0;
+        for _ in 0..4 {
+            for event in battle.tick() {
+                if let crate::events::BattleEvent::Move { actor_id, .. } = event {
+                    if actor_id == 0 {
+                        mouse_moves += 1;
+                    } else {
+                        turtle_moves += 1;
+                    }
+                }
+            }
+        }
+        
+        assert!(mouse_moves > turtle_moves * 2,
+            "Mouse should out-move the turtle ({} vs {})", mouse_moves, turtle_moves);
+    }
+    
+    #[test]
+    fn test_lost_speed_reduces_energy_gain() {
+        use crate::sim::Actor;
+        
+        let mut healthy = Actor::new(0, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        let mut crippled = healthy.clone();
+        healthy.speed = 6;
+        crippled.speed = 3;
+        
+        healthy.gain_energy();
+        crippled.gain_energy();
+        
+        assert!(healthy.can_afford(crate::sim::Action::ATTACK_COST));
+        assert!(!crippled.can_afford(crate::sim::Action::MOVE_COST));
+    }
+    
+    #[test]
+    fn test_immobile_actors_still_build_energy_to_attack() {
+        use crate::sim::Actor;
+        
+        let mut rooted = Actor::new(0, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        rooted.speed = 0;
+        
+        for _ in 0..crate::sim::Action::ATTACK_COST {
+            rooted.gain_energy();
+        }
+        
+        assert!(rooted.can_afford(crate::sim::Action::ATTACK_COST),
+            "A speed 0 actor should eventually afford an attack");
+    }
+    
+    #[test]
+    fn test_legless_actors_bank_energy_until_an_enemy_comes_close() {
+        use rand::rngs::SmallRng;
+        use rand_seeder::Seeder;
+        use crate::anatomy::part::Part;
+        use crate::sim::{Action, Actor, SimpleAI};
+        use crate::sim::attack::AttackTemplate;
+        
+        let table = AttackTemplate::load_test_table();
+        let mut rng: SmallRng = Seeder::from(7u64).make_rng();
+        let mut rooted = Actor::new(0, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        let mut jaw = Part::new("jaw".to_string(), "Jaw".to_string());
+        jaw.tags = vec!["bite_weapon".to_string()];
+        rooted.parts.push(jaw);
+        rooted.speed = 0;
+        rooted.stamina = 100;
+        let mut enemy = Actor::new(1, "test".to_string(), 'E', "white".to_string(), 1, 5, 0);
+        
+        // Out of reach it waits, which costs nothing, instead of trying to walk
+        for _ in 0..Action::ATTACK_COST {
+            let action = SimpleAI::select_action(&mut rng, &rooted, &[], std::slice::from_ref(&enemy), &table);
+            assert!(matches!(action, Some(Action::Wait { .. })));
+            rooted.gain_energy();
+        }
+        assert!(rooted.can_afford(Action::ATTACK_COST));
+        
+        // Once the enemy steps adjacent, the banked energy goes into a bite
+        enemy.x = 1;
+        let mut attacked = false;
+        for _ in 0..20 {
+            let action = SimpleAI::select_action(&mut rng, &rooted, &[], std::slice::from_ref(&enemy), &table);
+            assert!(!matches!(action, Some(Action::Move { .. })));
+            attacked |= matches!(action, Some(Action::Attack { target_id: 1, .. }));
+        }
+        assert!(attacked);
+    }
+    
+    #[test]
+    fn test_flyers_tire_while_airborne() {
+        use crate::sim::Actor;
+        
+        let mut flyer = Actor::new(0, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        flyer.airborne = true;
+        let start = flyer.stamina;
+        
+        assert!(flyer.recover_stamina());
+        assert!(flyer.stamina < start, "Flying should drain stamina faster than it recovers");
+        
+        while flyer.recover_stamina() {}
+        assert!(flyer.stamina < flyer.flight_stamina_cost(), "Flyers only come down once exhausted");
+    }
+    
+    #[test]
+    fn test_large_armies_spawn_on_unique_cells() {
+        let mut battle = Battle::new(99);
+        
+        let ant = r#"{"species_id": "ant"}"#;
+        let team_a_json = format!(r#"{{"formation": "wedge", "members": [{}]}}"#, vec![ant; 40].join(","));
+        let team_b_json = format!("[{}]", vec![r#"{"species_id": "gerbil", "formation": "cluster"}"#; 40].join(","));
+        
+        let result = battle.init_with_species("../data/species", &team_a_json, &team_b_json);
+        assert!(result.is_ok(), "Failed to initialize battle: {:?}", result);
+        
+        let state = serde_json::to_value(&battle).unwrap();
+        let mut cells = std::collections::HashSet::new();
+        for team in ["team_a", "team_b"] {
+            for actor in state[team].as_array().unwrap() {
+                let cell = (actor["x"].as_i64().unwrap(), actor["y"].as_i64().unwrap());
+                assert!(cells.insert(cell), "Two actors spawned on {:?}", cell);
+            }
+        }
+        assert_eq!(cells.len(), 80);
+    }
+    
+    #[test]
+    fn test_explicit_spawn_positions() {
+        let mut battle = Battle::new(5);
+        
+        let team_a_json = r#"[{"species_id": "wolf", "position": {"x": 4, "y": 2}}, {"species_id": "wolf"}]"#;
+        let team_b_json = r#"[{"species_id": "bear", "position": {"x": 4, "y": 2}}]"#;
+        
+        // Two combatants can't be placed on the same explicit cell
+        let result = battle.init_with_species("../data/species", team_a_json, team_b_json);
+        assert!(result.is_err());
+        
+        let mut battle = Battle::new(5);
+        let team_b_json = r#"[{"species_id": "bear", "position": {"x": 10, "y": 6}}]"#;
+        battle.init_with_species("../data/species", team_a_json, team_b_json).unwrap();
+        
+        let state = serde_json::to_value(&battle).unwrap();
+        assert_eq!(state["team_a"][0]["x"], 4);
+        assert_eq!(state["team_a"][0]["y"], 2);
+        assert_eq!(state["team_b"][0]["x"], 10);
+        assert_eq!(state["team_b"][0]["y"], 6);
+    }
+    
+    #[test]
+    fn test_arena_size_exposed_before_first_tick() {
+        let mut battle = Battle::new(3);
+        
+        let team_a_json = format!("[{}]", vec![r#"{"species_id": "ant"}"#; 35].join(","));
+        let team_b_json = r#"[{"species_id": "dragon"}, {"species_id": "dragon"}]"#;
+        battle.init_with_species("../data/species", &team_a_json, team_b_json).unwrap();
+        
+        let state = serde_json::to_value(&battle).unwrap();
+        assert_eq!(state["tick_count"], 0);
+        assert_eq!(state["arena"]["width"], state["grid"]["width"]);
+        assert_eq!(state["arena"]["height"], state["grid"]["height"]);
+        assert!(state["arena"]["height"].as_i64().unwrap() > 8, "35 ants need more than the minimum arena");
+    }
+    
+    #[test]
+    fn test_ground_layer_records_corpses_and_gore() {
+        let mut battle = Battle::new(42);
+        
+        let team_a_json = r#"[{"species_id": "chicken"}, {"species_id": "chicken"}, {"species_id": "chicken"}]"#;
+        let team_b_json = r#"[{"species_id": "baboon"}]"#;
+        battle.init_with_species("../data/species", team_a_json, team_b_json).unwrap();
+        
+        let mut deaths = 0;
+        let mut bled = false;
+        let mut tick_count = 0;
+        while !battle.is_finished() && tick_count < 1000 {
+            for event in battle.tick() {
+                match event {
+                    crate::events::BattleEvent::Death { .. } => deaths += 1,
+                    crate::events::BattleEvent::Bleed { .. } => bled = true,
+                    _ => {}
+                }
+            }
+            tick_count += 1;
+        }
+        
+        let state = serde_json::to_value(&battle).unwrap();
+        let ground = state["grid"]["ground"].as_array().unwrap();
+        let corpses = ground.iter().filter(|e| e["kind"] == "corpse").count();
+        let blood = ground.iter().any(|e| e["kind"] == "decal" && e["decal"] == "blood");
+        
+        assert_eq!(corpses, deaths, "Every death should leave a corpse");
+        assert_eq!(blood, bled, "Bleeding should stain the ground");
+    }
+    
+    #[test]
+    fn test_ethereal_actors_dissipate_without_remains() {
+        use crate::events::BattleEvent;
+        
+        let mut battle = Battle::new(3);
+        
+        let team_a_json = r#"[{"species_id": "wolf"}]"#;
+        let team_b_json = r#"[{"species_id": "space_void"}]"#;
+        battle.init_with_species("../data/species", team_a_json, team_b_json).unwrap();
+        
+        let mut dissipated = false;
+        let mut tick_count = 0;
+        while !battle.is_finished() && tick_count < 1000 {
+            for event in battle.tick() {
+                match event {
+                    BattleEvent::Dissipate { .. } => dissipated = true,
+                    BattleEvent::Death { actor_id, .. } => assert_eq!(actor_id, 0, "The void must not die like flesh"),
+                    BattleEvent::Sever { actor_id, .. } | BattleEvent::Bleed { actor_id, .. } => {
+                        assert_eq!(actor_id, 0, "Ethereal parts neither gib nor bleed");
+                    }
+                    _ => {}
+                }
+            }
+            tick_count += 1;
+        }
+        
+        assert!(dissipated);
+        let state = serde_json::to_value(&battle).unwrap();
+        let ground = state["grid"]["ground"].as_array().unwrap();
+        assert!(!ground.iter().any(|e| e["kind"] == "corpse" && e["species_id"] == "space_void"));
+    }
+    
+    #[test]
+    fn test_demonic_fear_aura_reports_enemies_entering_and_leaving() {
+        use crate::events::BattleEvent;
+        use crate::sim::aura::AuraKind;
+        use std::collections::HashSet;
+        
+        let mut battle = Battle::new(1);
+        
+        let team_a_json = r#"[{"species_id": "demon"}]"#;
+        let team_b_json = r#"[{"species_id": "wolf"}, {"species_id": "wolf"}, {"species_id": "wolf"}]"#;
+        battle.init_with_species("../data/species", team_a_json, team_b_json).unwrap();
+        
+        let mut inside = HashSet::new();
+        let mut left = 0;
+        let mut tick_count = 0;
+        while !battle.is_finished() && tick_count < 1000 {
+            for event in battle.tick() {
+                match event {
+                    BattleEvent::AuraEnter { actor_id, source_id, aura } => {
+                        assert_eq!((source_id, aura), (0, AuraKind::Fear), "Only the demon radiates fear");
+                        assert_ne!(actor_id, 0, "The demon doesn't fear itself");
+                        assert!(inside.insert(actor_id), "Entered twice without leaving");
+                    }
+                    BattleEvent::AuraLeave { actor_id, .. } => {
+                        assert!(inside.remove(&actor_id), "Left an aura it never entered");
+                        left += 1;
+                    }
+                    _ => {}
+                }
+            }
+            tick_count += 1;
+        }
+        
+        assert!(left > 0, "The fear should lift once the demon falls");
+    }
+    
+    #[test]
+    fn test_grasping_actors_grapple_and_held_actors_stay_put() {
+        use crate::events::BattleEvent;
+        use std::collections::HashSet;
+        
+        let mut battle = Battle::new(7);
+        
+        let team_a_json = r#"[{"species_id": "chimpanzee"}, {"species_id": "chimpanzee"}]"#;
+        let team_b_json = r#"[{"species_id": "cat"}, {"species_id": "cat"}, {"species_id": "cat"}, {"species_id": "cat"}]"#;
+        battle.init_with_species("../data/species", team_a_json, team_b_json).unwrap();
+        
+        let mut held: HashSet<u32> = HashSet::new();
+        let mut grapples = 0;
+        let mut tick_count = 0;
+        while !battle.is_finished() && tick_count < 1000 {
+            for event in battle.tick() {
+                match event {
+                    BattleEvent::Grapple { target_id, .. } => {
+                        grapples += 1;
+                        held.insert(target_id);
+                    }
+                    BattleEvent::BreakFree { actor_id, .. }
+                    | BattleEvent::Death { actor_id, .. } => {
+                        held.remove(&actor_id);
+                    }
+                    BattleEvent::StatusChange { actor_id, ref status, active: false } if status == "grappled" => {
+                        held.remove(&actor_id);
+                    }
+                    BattleEvent::Move { actor_id, .. } => {
+                        assert!(!held.contains(&actor_id), "Held actor {} moved on its own", actor_id);
+                    }
+                    _ => {}
+                }
+            }
+            tick_count += 1;
+        }
+        
+        assert!(grapples > 0, "Chimpanzees should grab the lighter cats");
+    }
+    
+    #[test]
+    fn test_reinforcement_waves_arrive_on_schedule() {
+        use crate::events::BattleEvent;
+        
+        let mut battle = Battle::new(42);
+        
+        let team_a_json = r#"[{"species_id": "wolf"}]"#;
+        let team_b_json = r#"{
+            "members": [{"species_id": "ant"}],
+            "waves": [{
+                "arrive_tick": 5,
+                "every": 5,
+                "repeat": 3,
+                "edge": "top",
+                "members": [{"species_id": "ant"}, {"species_id": "ant"}, {"species_id": "ant"}]
+            }]
+        }"#;
+        battle.init_with_species("../data/species", team_a_json, team_b_json).unwrap();
+        
+        let state = serde_json::to_value(&battle).unwrap();
+        assert_eq!(state["team_b"].as_array().unwrap().len(), 1);
+        assert_eq!(state["reinforcements"].as_array().unwrap().len(), 9);
+        
+        let mut arrivals: Vec<(u64, i32)> = Vec::new();
+        let mut tick_count = 0;
+        while !battle.is_finished() && tick_count < 1000 {
+            let events = battle.tick();
+            tick_count += 1;
+            for event in events {
+                if let BattleEvent::Spawn { team, y, .. } = event {
+                    assert_eq!(team, 1);
+                    arrivals.push((tick_count, y));
+                }
+            }
+        }
+        
+        let ticks: Vec<u64> = arrivals.iter().map(|&(t, _)| t).collect();
+        assert_eq!(ticks, vec![5, 5, 5, 10, 10, 10, 15, 15, 15]);
+        assert!(arrivals.iter().all(|&(_, y)| y <= 4), "Waves should enter from the top edge");
+        assert!(battle.is_finished() && tick_count >= 15, "Team B can't lose while reinforcements are pending");
+    }
+    
+    #[test]
+    fn test_repeating_wave_needs_interval() {
+        let mut battle = Battle::new(1);
+        let team_b_json = r#"{"members": [], "waves": [{"arrive_tick": 5, "repeat": 2, "members": [{"species_id": "ant"}]}]}"#;
+        let result = battle.init_with_species("../data/species", r#"[{"species_id": "wolf"}]"#, team_b_json);
+        assert!(result.is_err());
+    }
+    
+    #[test]
+    fn test_only_fighting_species_are_validated() {
+        let dir = std::env::temp_dir().join(format!("eab_validate_{}", std::process::id()));
+        let species_dir = dir.join("species");
+        std::fs::create_dir_all(&species_dir).unwrap();
+        std::fs::copy("../data/attacks.yaml", dir.join("attacks.yaml")).unwrap();
+        std::fs::copy("../data/species/chicken.yaml", species_dir.join("chicken.yaml")).unwrap();
+        
+        // A species whose torso provides an attack missing from the table
+        let chicken = std::fs::read_to_string("../data/species/chicken.yaml").unwrap();
+        let broken = chicken
+            .replacen("id: \"chicken\"", "id: \"broken\"", 1)
+            .replacen("    hit_weight: 8\n", "    hit_weight: 8\n    attacks_provided:\n      - attack: laser\n", 1);
+        std::fs::write(species_dir.join("broken.yaml"), broken).unwrap();
+        let species_dir = species_dir.to_str().unwrap();
+        
+        let chickens = r#"[{"species_id": "chicken"}]"#;
+        assert!(Battle::new(1).init_with_species(species_dir, chickens, chickens).is_ok());
+        
+        let result = Battle::new(1).init_with_species(species_dir, chickens, r#"[{"species_id": "broken"}]"#);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(result.unwrap_err().contains("unknown attack 'laser'"));
+    }
+    
+    #[test]
+    fn test_counted_parts_attach_to_matching_instance() {
+        let mut battle = Battle::new(7);
+        battle.init_with_species("../data/species", r#"[{"species_id": "chicken"}]"#, r#"[{"species_id": "goose"}]"#).unwrap();
+        
+        let state = serde_json::to_value(&battle).unwrap();
+        let chicken_parts = state["team_a"][0]["parts"].as_array().unwrap();
+        let attachments_of = |part_id: &str| {
+            chicken_parts
+                .iter()
+                .find(|p| p["part_id"] == part_id)
+                .map(|p| p["attachments"].clone())
+                .unwrap()
+        };
+        assert_eq!(attachments_of("claw_0"), serde_json::json!(["leg_0"]));
+        assert_eq!(attachments_of("claw_1"), serde_json::json!(["leg_1"]));
+        assert_eq!(attachments_of("leg_1"), serde_json::json!(["torso"]));
+        
+        // A goose's head hangs from its neck
+        let goose_parts = state["team_b"][0]["parts"].as_array().unwrap();
+        let head = goose_parts.iter().find(|p| p["part_id"] == "head").unwrap();
+        assert_eq!(head["attachments"], serde_json::json!(["neck"]));
+    }
+    
+    /// FNV-1a over the JSON of every event. Stable across platforms and
+    /// Rust releases, unlike `DefaultHasher`.
+    fn hash_event_stream(seed: u64, team_a_json: &str, team_b_json: &str) -> (u64, usize) {
+        let mut battle = Battle::new(seed);
+        battle.init_with_species("../data/species", team_a_json, team_b_json).unwrap();
+        
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut event_count = 0;
+        let mut tick_count = 0;
+        while !battle.is_finished() && tick_count < 1000 {
+            for event in battle.tick() {
+                for byte in serde_json::to_vec(&event).unwrap() {
+                    hash ^= byte as u64;
+                    hash = hash.wrapping_mul(0x0100_0000_01b3);
+                }
+                event_count += 1;
+            }
+            tick_count += 1;
+        }
+        
+        (hash, event_count)
+    }
+    
+    /// Replays must produce the exact same event stream on every platform.
+    /// If a deliberate simulation change alters this hash, update the
+    /// expected values below in the same commit.
+    #[test]
+    fn test_golden_event_stream_hash() {
+        let team_a_json = r#"[{"species_id": "chimpanzee"}, {"species_id": "chicken"}, {"species_id": "goose"}]"#;
+        let team_b_json = r#"[{"species_id": "wolf"}, {"species_id": "cat"}, {"species_id": "rat"}]"#;
+        
+        let first = hash_event_stream(20240601, team_a_json, team_b_json);
+        let second = hash_event_stream(20240601, team_a_json, team_b_json);
+        assert_eq!(first, second, "Same seed must give the same event stream");
+        
+        assert_eq!(first, (6627959239611699693, 480), "Event stream changed for a fixed seed and matchup");
+    }
 }
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use crate::sim::{Actor, CombatResolver};
+use crate::events::BattleEvent;
+
+/// Venom a fang carries per point of its health. A victim's toxin load rises
+/// by the dose divided by its mass in grams, so a snake that floods a mouse
+/// barely registers in a horse.
+pub const VENOM_DOSE_PER_HP: u32 = 20_000;
+/// Ceiling on accumulated toxin
+pub const MAX_TOXIN: u32 = 100;
+/// Toxin at which poisoning turns severe
+pub const SEVERE_TOXIN: u32 = 30;
+/// Toxin at which the victim is paralyzed
+pub const PARALYSIS_TOXIN: u32 = 60;
+/// Toxin metabolized every tick
+pub const TOXIN_METABOLISM: u32 = 3;
+/// Toxin that costs one HP per tick
+pub const TOXIN_PER_HP: u32 = 15;
+
+/// How badly an actor is poisoned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToxinSeverity {
+    Clear,
+    Mild,
+    Severe,
+    Paralyzing,
+}
+
+impl ToxinSeverity {
+    /// Severity band for a toxin load
+    pub fn from_toxin(toxin: u32) -> Self {
+        if toxin >= PARALYSIS_TOXIN {
+            ToxinSeverity::Paralyzing
+        } else if toxin >= SEVERE_TOXIN {
+            ToxinSeverity::Severe
+        } else if toxin > 0 {
+            ToxinSeverity::Mild
+        } else {
+            ToxinSeverity::Clear
+        }
+    }
+}
+
+pub struct VenomResolver;
+
+impl VenomResolver {
+    /// Toxin load a dose adds to a victim of this mass
+    pub fn toxin_from_dose(dose: u32, mass_g: u32) -> u32 {
+        (dose as u64 / mass_g.max(1) as u64).min(MAX_TOXIN as u64) as u32
+    }
+    
+    /// Inject venom from a hit. Stacks with venom already in the blood;
+    /// paralyzed flyers fall.
+    pub fn envenom(actor: &mut Actor, dose: u32) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        let added = Self::toxin_from_dose(dose, actor.mass_g);
+        if added == 0 || !actor.is_alive() || actor.is_bloodless() {
+            return events;
+        }
+        
+        let before = ToxinSeverity::from_toxin(actor.toxin);
+        actor.toxin = (actor.toxin + added).min(MAX_TOXIN);
+        let after = ToxinSeverity::from_toxin(actor.toxin);
+        
+        if after != before {
+            events.push(BattleEvent::Poison {
+                actor_id: actor.id,
+                severity: after,
+            });
+            if after == ToxinSeverity::Paralyzing {
+                actor.energy = 0;
+                events.extend(CombatResolver::fall_from_sky(actor));
+            }
+        }
+        
+        events
+    }
+    
+    /// Let the toxin work for a tick: it eats at health, then is slowly
+    /// metabolized. Reports each drop in severity, ending with `Clear`.
+    pub fn apply_toxin(actor: &mut Actor) -> Vec<BattleEvent> {
+        let mut events = Vec::new();
+        
+        if actor.toxin == 0 {
+            return events;
+        }
+        
+        let damage = actor.toxin / TOXIN_PER_HP;
+        if damage > 0 {
+            actor.take_damage(damage as i32);
+            events.push(BattleEvent::ToxinDamage {
+                actor_id: actor.id,
+                amount: damage,
+            });
+            if !actor.is_alive() {
+                events.push(BattleEvent::Death {
+                    actor_id: actor.id,
+                    x: actor.x,
+                    y: actor.y,
+                });
+                return events;
+            }
+        }
+        
+        let before = ToxinSeverity::from_toxin(actor.toxin);
+        actor.toxin = actor.toxin.saturating_sub(TOXIN_METABOLISM);
+        let after = ToxinSeverity::from_toxin(actor.toxin);
+        if after != before {
+            events.push(BattleEvent::Poison {
+                actor_id: actor.id,
+                severity: after,
+            });
+        }
+        
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn create_actor(mass_g: u32) -> Actor {
+        let mut actor = Actor::new(1, "test".to_string(), 'T', "white".to_string(), 0, 0, 0);
+        actor.mass_g = mass_g;
+        actor
+    }
+    
+    fn severities(events: &[BattleEvent]) -> Vec<ToxinSeverity> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                BattleEvent::Poison { severity, .. } => Some(*severity),
+                _ => None,
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn test_mass_scales_venom_resistance() {
+        let snake_bite = 12 * VENOM_DOSE_PER_HP;
+        let mut mouse = create_actor(20);
+        let mut horse = create_actor(500_000);
+        
+        let events = VenomResolver::envenom(&mut mouse, snake_bite);
+        assert_eq!(severities(&events), vec![ToxinSeverity::Paralyzing]);
+        assert!(mouse.is_incapacitated());
+        
+        assert!(VenomResolver::envenom(&mut horse, snake_bite).is_empty());
+        assert_eq!(horse.toxin, 0);
+    }
+    
+    #[test]
+    fn test_toxin_stacks_and_escalates() {
+        let mut cat = create_actor(4_500);
+        let bite = 6 * VENOM_DOSE_PER_HP;
+        
+        assert_eq!(severities(&VenomResolver::envenom(&mut cat, bite)), vec![ToxinSeverity::Mild]);
+        assert_eq!(severities(&VenomResolver::envenom(&mut cat, bite)), vec![ToxinSeverity::Severe]);
+        assert_eq!(severities(&VenomResolver::envenom(&mut cat, bite)), vec![ToxinSeverity::Paralyzing]);
+        assert!(cat.is_paralyzed());
+        assert!(cat.stamina_regen() < create_actor(4_500).stamina_regen());
+    }
+    
+    #[test]
+    fn test_toxin_wears_off_while_doing_damage() {
+        let mut actor = create_actor(1_000);
+        actor.toxin = 45;
+        
+        let mut seen = Vec::new();
+        while actor.toxin > 0 {
+            seen.extend(severities(&VenomResolver::apply_toxin(&mut actor)));
+        }
+        
+        assert_eq!(seen, vec![ToxinSeverity::Mild, ToxinSeverity::Clear]);
+        assert!(actor.hp < actor.max_hp);
+        assert!(actor.is_alive());
+    }
+}
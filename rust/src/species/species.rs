@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use crate::sim::Attack;
+use crate::sim::{Attack, AttackOverride, Weapon};
+use crate::sim::attack::AttackTemplate;
+use crate::sim::aura::Aura;
+use crate::anatomy::part::{BodyZone, Resistances};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Species {
@@ -10,6 +13,9 @@ pub struct Species {
     pub color: String,
     pub base_stats: BaseStats,
     pub parts: Vec<PartDefinition>,
+    /// Auras the species radiates over nearby actors
+    #[serde(default)]
+    pub auras: Vec<Aura>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +28,14 @@ pub struct BaseStats {
     pub aggression: u32,
 }
 
+impl BaseStats {
+    /// Mass in whole grams, the unit the simulation works in. Converted once
+    /// at load so no float math reaches the battle itself.
+    pub fn mass_g(&self) -> u32 {
+        (self.mass_kg * 1000.0).round().max(1.0) as u32
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PartDefinition {
     pub part_id: String,
@@ -33,6 +47,16 @@ pub struct PartDefinition {
     pub armor: i32,
     pub bleed_rate: u32,
     pub hit_weight: u32,
+    /// Explicit resistance table; defaults from covering tags when omitted
+    #[serde(default)]
+    pub resistances: Option<Resistances>,
+    /// Explicit body height (low, mid, high); inferred from tags when omitted
+    #[serde(default)]
+    pub zone: Option<BodyZone>,
+    /// Attacks from the attack table, in place of those the part's weapon
+    /// tags would derive
+    #[serde(default)]
+    pub attacks_provided: Option<Vec<AttackOverride>>,
 }
 
 impl Species {
@@ -51,6 +75,7 @@ impl Species {
                 aggression: 50,
             },
             parts: Vec::new(),
+            auras: Vec::new(),
         }
     }
     
@@ -85,18 +110,21 @@ impl Species {
     }
     
     /// Derive all available attacks from weapon parts
-    pub fn derive_attacks(&self) -> Vec<Attack> {
+    pub fn derive_attacks(&self, table: &[AttackTemplate]) -> Vec<Attack> {
         let mut attacks = Vec::new();
         
+        let body_hp = self.parts.iter().map(|p| p.hp * p.count as i32).sum();
+        
         for part in &self.parts {
-            if let Some(attack) = Attack::derive_from_tags(
+            attacks.extend(Attack::derive_for_part(
+                table,
                 &part.part_id,
                 &part.display_name,
                 &part.tags,
                 part.hp,
-            ) {
-                attacks.push(attack);
-            }
+                Weapon::new(self.base_stats.mass_g(), part.hp, body_hp),
+                part.attacks_provided.as_deref(),
+            ));
         }
         
         attacks
@@ -132,6 +160,18 @@ impl Species {
             }
         }
         
+        // Auras need a reach and, if tied to a tag, a part that carries it
+        for aura in &self.auras {
+            if aura.radius < 1 {
+                errors.push(format!("Aura {:?} must have a radius of at least 1", aura.kind));
+            }
+            if let Some(tag) = &aura.tag {
+                if !self.has_part_with_tag(tag) {
+                    errors.push(format!("Aura {:?} is granted by tag '{}' that no part has", aura.kind, tag));
+                }
+            }
+        }
+        
         // Check for duplicate part IDs
         let mut seen_ids = HashMap::new();
         for part in &self.parts {
@@ -161,6 +201,9 @@ impl PartDefinition {
             armor: 0,
             bleed_rate: 0,
             hit_weight: 1,
+            resistances: None,
+            zone: None,
+            attacks_provided: None,
         }
     }
     
@@ -199,6 +242,11 @@ impl PartDefinition {
         self
     }
     
+    pub fn with_resistances(mut self, resistances: Resistances) -> Self {
+        self.resistances = Some(resistances);
+        self
+    }
+    
     pub fn has_tag(&self, tag: &str) -> bool {
         self.tags.iter().any(|t| t == tag)
     }
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::species::{Species, SpeciesLoader, SpeciesValidator};
+    use crate::sim::attack::AttackTemplate;
     use std::fs;
     use std::path::PathBuf;
     
@@ -66,7 +67,7 @@ mod tests {
         }
         
         let chicken = loader.load_from_file(&path).unwrap();
-        let validation = validator.validate(&chicken);
+        let validation = validator.validate(&chicken, &AttackTemplate::load_test_table());
         
         assert!(validation.is_ok(), "Chicken species should validate: {:?}", validation.err());
     }
@@ -87,9 +88,12 @@ mod tests {
             armor: 0,
             bleed_rate: 0,
             hit_weight: 1,
+            resistances: None,
+            zone: None,
+            attacks_provided: None,
         });
         
-        let validation = validator.validate(&invalid_species);
+        let validation = validator.validate(&invalid_species, &[]);
         assert!(validation.is_err(), "Species without vital parts should fail validation");
         
         let errors = validation.err().unwrap();
@@ -110,8 +114,9 @@ mod tests {
             return;
         }
         
+        let table = AttackTemplate::load_test_table();
         let chicken = loader.load_from_file(&path).unwrap();
-        let attacks = chicken.derive_attacks();
+        let attacks = chicken.derive_attacks(&table);
         
         assert!(!attacks.is_empty(), "Chicken should have at least one attack");
         
@@ -123,6 +128,55 @@ mod tests {
         assert!(has_scratch, "Chicken should have scratch attack");
     }
     
+    #[test]
+    fn test_attack_table_derives_every_weapon_tag() {
+        use crate::sim::attack::AttackType;
+        use crate::sim::{Attack, Weapon};
+        
+        let table = AttackTemplate::load_test_table();
+        for template in &table {
+            assert_eq!(table.iter().filter(|t| t.id == template.id).count(), 1, "Duplicate template '{}'", template.id);
+            assert!(template.range >= 1, "Template '{}' must reach adjacent cells", template.id);
+            if !matches!(template.attack_type, AttackType::Hold) {
+                assert!(template.damage_percent > 0, "Template '{}' needs a damage formula", template.id);
+            }
+        }
+        
+        let tags = vec!["torso".to_string(), "ram_weapon".to_string()];
+        let rock = Weapon::new(600_000, 90, 300);
+        let ram = Attack::derive_from_tags(&table, "torso", "Torso", &tags, 90, rock);
+        assert_eq!(ram.len(), 1);
+        assert!(matches!(ram[0].attack_type, AttackType::Ram));
+        assert_eq!(ram[0].damage.base_damage, 12);
+        assert!(Attack::derive_from_tags(&table, "torso", "Torso", &tags[..1], 90, rock).is_empty());
+    }
+    
+    #[test]
+    fn test_parts_provide_several_attacks() {
+        let mut loader = SpeciesLoader::new();
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("data/species/alligator.yaml");
+        
+        if !path.exists() {
+            eprintln!("Warning: alligator.yaml not found, skipping test");
+            return;
+        }
+        
+        let table = AttackTemplate::load_test_table();
+        let mut alligator = loader.load_from_file(&path).unwrap();
+        let attacks = alligator.derive_attacks(&table);
+        let jaw: Vec<&str> = attacks.iter().map(|a| a.attack_id.as_str()).filter(|id| id.starts_with("jaw")).collect();
+        assert_eq!(jaw, vec!["jaw_bite", "jaw_hold"], "The jaw bites and holds");
+        assert!(attacks.iter().any(|a| a.is_hold()));
+        assert!(SpeciesValidator::new().validate(&alligator, &table).is_ok());
+        
+        alligator.parts[2].attacks_provided.as_mut().unwrap()[0].attack = "tail_whip".to_string();
+        let errors = SpeciesValidator::new().validate(&alligator, &table).err().unwrap();
+        assert!(errors.iter().any(|e| e.contains("tail_whip")));
+    }
+    
     #[test]
     fn test_species_loader_caching() {
         let mut loader = SpeciesLoader::new();
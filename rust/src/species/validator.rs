@@ -1,4 +1,5 @@
 use crate::species::Species;
+use crate::sim::attack::AttackTemplate;
 use std::collections::{HashMap, HashSet};
 
 pub struct SpeciesValidator {
@@ -14,8 +15,9 @@ impl SpeciesValidator {
         Self { required_tags }
     }
     
-    /// Validate a species definition
-    pub fn validate(&self, species: &Species) -> Result<(), Vec<String>> {
+    /// Validate a species definition against the attack table its parts
+    /// draw on
+    pub fn validate(&self, species: &Species, attack_table: &[AttackTemplate]) -> Result<(), Vec<String>> {
         let mut errors = Vec::new();
         
         // Check basic fields
@@ -42,7 +44,7 @@ impl SpeciesValidator {
         self.validate_part_graph(species, &mut errors);
         
         // Validate part definitions
-        self.validate_parts(species, &mut errors);
+        self.validate_parts(species, attack_table, &mut errors);
         
         // Validate base stats
         self.validate_base_stats(species, &mut errors);
@@ -104,7 +106,7 @@ impl SpeciesValidator {
         }
     }
     
-    fn validate_parts(&self, species: &Species, errors: &mut Vec<String>) {
+    fn validate_parts(&self, species: &Species, attack_table: &[AttackTemplate], errors: &mut Vec<String>) {
         for part in &species.parts {
             // Validate part_id
             if part.part_id.is_empty() {
@@ -131,13 +133,13 @@ impl SpeciesValidator {
                 errors.push(format!("Part '{}' must have non-zero hit_weight", part.part_id));
             }
             
-            // Check weapon parts have appropriate tags
-            if part.has_tag("peck_weapon") || part.has_tag("bite_weapon") 
-                || part.has_tag("scratch_weapon") || part.has_tag("sting_weapon") {
-                // Weapon parts should generally have sharp or blunt tags
-                if !part.has_tag("sharp") && !part.has_tag("blunt") {
-                    // This is a warning, not an error
-                    eprintln!("Warning: Weapon part '{}' has no sharp/blunt tag", part.part_id);
+            // Provided attacks must come from the attack table and reach
+            for provided in part.attacks_provided.iter().flatten() {
+                if AttackTemplate::find(attack_table, &provided.attack).is_none() {
+                    errors.push(format!("Part '{}' provides unknown attack '{}'", part.part_id, provided.attack));
+                }
+                if provided.range == Some(0) {
+                    errors.push(format!("Part '{}' provides attack '{}' with no reach", part.part_id, provided.attack));
                 }
             }
         }
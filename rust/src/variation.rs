@@ -5,34 +5,34 @@ pub struct VariationGenerator;
 
 impl VariationGenerator {
     /// Generate stat variation for a combatant
-    /// Returns multipliers for HP, speed, and stamina
-    /// Typical range: 0.8 - 1.2 (±20%)
-    /// Rare standouts (~1/1000): 1.3 - 1.5 (30-50% boost)
-    pub fn generate_stat_variation<R: Rng>(rng: &mut R) -> (f32, f32, f32) {
+    /// Returns percent multipliers for HP, speed, and stamina (100 = unchanged)
+    /// Typical range: 80 - 120 (±20%)
+    /// Rare standouts (~1/1000): 130 - 150 (30-50% boost)
+    pub fn generate_stat_variation<R: Rng>(rng: &mut R) -> (u32, u32, u32) {
         // Roll for rare standout individual (0.1% chance)
-        if rng.gen::<f32>() < 0.001 {
+        if rng.gen_range(0..1000) == 0 {
             return Self::generate_standout(rng);
         }
         
         // Normal variation: ±20%
-        let hp_mult = rng.gen_range(0.8..1.2);
-        let speed_mult = rng.gen_range(0.8..1.2);
-        let stamina_mult = rng.gen_range(0.8..1.2);
+        let hp_mult = rng.gen_range(80..120);
+        let speed_mult = rng.gen_range(80..120);
+        let stamina_mult = rng.gen_range(80..120);
         
         (hp_mult, speed_mult, stamina_mult)
     }
     
     /// Generate standout individual with exceptional stats
-    fn generate_standout<R: Rng>(rng: &mut R) -> (f32, f32, f32) {
+    fn generate_standout<R: Rng>(rng: &mut R) -> (u32, u32, u32) {
         // Standout individuals get 30-50% boost in 1-2 stats
         let boost_count = rng.gen_range(1..=2);
         
-        let mut hp_mult = 1.0;
-        let mut speed_mult = 1.0;
-        let mut stamina_mult = 1.0;
+        let mut hp_mult = 100;
+        let mut speed_mult = 100;
+        let mut stamina_mult = 100;
         
         for _ in 0..boost_count {
-            let boost = rng.gen_range(1.3..1.5);
+            let boost = rng.gen_range(130..150);
             match rng.gen_range(0..3) {
                 0 => hp_mult = boost,
                 1 => speed_mult = boost,
@@ -50,15 +50,15 @@ impl VariationGenerator {
         let mut injuries = Vec::new();
         
         // 10% chance of injuries
-        if rng.gen::<f32>() < 0.1 && !part_ids.is_empty() {
+        if rng.gen_range(0..100) < 10 && !part_ids.is_empty() {
             let injury_count = rng.gen_range(1..=2);
             
             for _ in 0..injury_count {
                 // Pick a random non-vital part
                 if let Some(part_id) = part_ids.iter().nth(rng.gen_range(0..part_ids.len())) {
                     // Minor injury: 20-40% of part HP
-                    let damage_percent = rng.gen_range(0.2..0.4);
-                    let damage = (10.0 * damage_percent) as i32; // Assuming avg part HP ~10
+                    let damage_percent = rng.gen_range(20..40);
+                    let damage = 10 * damage_percent / 100; // Assuming avg part HP ~10
                     
                     injuries.push((part_id.clone(), damage));
                 }
@@ -69,8 +69,8 @@ impl VariationGenerator {
     }
     
     /// Check if variation makes this a "named" standout individual
-    pub fn is_standout(hp_mult: f32, speed_mult: f32, stamina_mult: f32) -> bool {
-        hp_mult > 1.25 || speed_mult > 1.25 || stamina_mult > 1.25
+    pub fn is_standout(hp_mult: u32, speed_mult: u32, stamina_mult: u32) -> bool {
+        hp_mult > 125 || speed_mult > 125 || stamina_mult > 125
     }
     
     /// Generate a name suffix for standout individuals
@@ -98,18 +98,18 @@ mod tests {
         // Test many generations to ensure range
         for _ in 0..100 {
             let (hp, speed, stamina) = VariationGenerator::generate_stat_variation(&mut rng);
-            assert!(hp >= 0.7 && hp <= 1.6);
-            assert!(speed >= 0.7 && speed <= 1.6);
-            assert!(stamina >= 0.7 && stamina <= 1.6);
+            assert!((70..=160).contains(&hp));
+            assert!((70..=160).contains(&speed));
+            assert!((70..=160).contains(&stamina));
         }
     }
     
     #[test]
     fn test_standout_detection() {
-        assert!(!VariationGenerator::is_standout(1.0, 1.0, 1.0));
-        assert!(!VariationGenerator::is_standout(1.2, 1.1, 1.0));
-        assert!(VariationGenerator::is_standout(1.3, 1.0, 1.0));
-        assert!(VariationGenerator::is_standout(1.0, 1.4, 1.0));
+        assert!(!VariationGenerator::is_standout(100, 100, 100));
+        assert!(!VariationGenerator::is_standout(120, 110, 100));
+        assert!(VariationGenerator::is_standout(130, 100, 100));
+        assert!(VariationGenerator::is_standout(100, 140, 100));
     }
     
     #[test]